@@ -1,2 +1,5 @@
 pub mod table;
-pub use table::{Column, Table, column, table};
+pub use table::{
+    Cell, CellRenderer, Column, Table, cell, column, column_group, custom_column,
+    drag_handle_column, live_cell, numeric, optional_column, table,
+};
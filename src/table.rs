@@ -1,9 +1,26 @@
 //! Display tables.
-use iced::advanced::widget::{Operation, tree};
-use iced::advanced::{self, Layout, Renderer as R, Widget, layout, overlay, renderer};
+pub mod testing;
+
+use iced::advanced::widget::{Id, Operation, operate, operation, tree};
+use iced::advanced::{self, Layout, Renderer as R, Widget, layout, overlay, renderer, text};
 use iced::alignment;
+use iced::border;
+use iced::keyboard;
 use iced::mouse;
-use iced::{Alignment, Background, Element, Length, Pixels, Rectangle, Size};
+use iced::time::{Duration, Instant};
+use iced::widget::{self, Space, container, tooltip};
+use iced::window;
+use iced::{
+    Alignment, Background, Border, Color, Element, Length, Pixels, Point, Rectangle, Size, Task,
+    Vector,
+};
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::ops::Range;
+use std::rc::Rc;
 
 /// Creates a new [`Table`] with the given columns and rows.
 ///
@@ -15,12 +32,195 @@ pub fn table<'a, 'b, T, Message, Theme, Renderer>(
 ) -> Table<'a, Message, Theme, Renderer>
 where
     T: Clone,
-    Theme: Catalog,
-    Renderer: R,
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: R + 'a,
+{
+    Table::new(columns, rows)
+}
+
+/// Creates a new [`Table`] with the given headers, building all the cells
+/// of a row at once with the given view function.
+///
+/// Useful when the cells of a row share some expensive computation. Rows
+/// producing fewer cells than headers are left blank.
+pub fn from_rows<'a, T, E, Message, Theme, Renderer>(
+    headers: impl IntoIterator<Item = impl Into<Element<'a, Message, Theme, Renderer>>>,
+    rows: impl IntoIterator<Item = T>,
+    view: impl Fn(&T) -> Vec<E>,
+) -> Table<'a, Message, Theme, Renderer>
+where
+    E: Into<Cell<'a, Message, Theme, Renderer>> + 'a,
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: R + 'a,
 {
+    let columns = headers.into_iter().enumerate().map(|(i, header)| {
+        optional_column(header, move |cells: Rc<RefCell<Vec<Option<E>>>>| {
+            cells.borrow_mut().get_mut(i).and_then(Option::take)
+        })
+    });
+
+    let rows = rows
+        .into_iter()
+        .map(|row| Rc::new(RefCell::new(view(&row).into_iter().map(Some).collect())));
+
     Table::new(columns, rows)
 }
 
+/// Creates a new [`Table`] with a text column for every field of the given
+/// [`Tabular`] rows.
+///
+/// Useful for prototyping, before writing the columns explicitly.
+pub fn auto<'a, T, Message, Theme, Renderer>(
+    rows: impl IntoIterator<Item = T>,
+) -> Table<'a, Message, Theme, Renderer>
+where
+    T: Tabular,
+    Message: 'a,
+    Theme: Catalog + widget::text::Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    let columns = T::FIELDS.iter().enumerate().map(|(i, field)| {
+        column(widget::text(*field), move |values: Rc<[String]>| {
+            widget::text(values.get(i).cloned().unwrap_or_default())
+        })
+    });
+
+    Table::new(columns, rows.into_iter().map(|row| Rc::from(row.values())))
+}
+
+/// Data that can be displayed in an [`auto`] table.
+pub trait Tabular {
+    /// The names of the fields, used as headers.
+    const FIELDS: &'static [&'static str];
+
+    /// Returns the values of the fields, in the same order as [`Tabular::FIELDS`].
+    fn values(&self) -> Vec<String>;
+}
+
+/// Creates a [`Table`] that is only rebuilt when the given version changes.
+///
+/// Useful for static tables in views that are updated frequently. The
+/// `view` function is only called again when the hash of `version` changes.
+pub fn cached<'a, Version, Message, Theme, Renderer>(
+    version: Version,
+    view: impl Fn(&Version) -> Table<'static, Message, Theme, Renderer> + 'a,
+) -> Element<'a, Message, Theme, Renderer>
+where
+    Version: Hash + 'a,
+    Message: 'static,
+    Theme: Catalog + 'static,
+    Renderer: R + 'static,
+{
+    widget::lazy(version, view).into()
+}
+
+/// Creates a new [`Table`] with a baseline row as its first row.
+///
+/// The baseline is drawn with a distinct background above the rest of the
+/// rows. Columns with a [`Column::delta`] show the difference of every other
+/// row with it.
+pub fn baseline<'a, 'b, T, Message, Theme, Renderer>(
+    columns: impl IntoIterator<Item = Column<'a, 'b, T, Message, Theme, Renderer>>,
+    baseline: T,
+    rows: impl IntoIterator<Item = T>,
+) -> Table<'a, Message, Theme, Renderer>
+where
+    'a: 'b,
+    T: Clone + 'b,
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: R + 'a,
+{
+    Table {
+        baseline: true,
+        ..Table::with_baseline(columns, Some(baseline), rows)
+    }
+}
+
+/// Creates a new [`Table`] comparing the given rows against a baseline.
+///
+/// Rows are matched by the given `key` function. Rows missing from the
+/// baseline are highlighted as added, rows missing from `rows` are kept in
+/// place and highlighted as removed, and matched rows that differ are
+/// highlighted as changed.
+pub fn compare<'a, 'b, T, K, Message, Theme, Renderer>(
+    columns: impl IntoIterator<Item = Column<'a, 'b, T, Message, Theme, Renderer>>,
+    baseline: impl IntoIterator<Item = T>,
+    rows: impl IntoIterator<Item = T>,
+    key: impl Fn(&T) -> K,
+) -> Table<'a, Message, Theme, Renderer>
+where
+    T: Clone + PartialEq,
+    K: Eq + Hash,
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: R + 'a,
+{
+    let rows: Vec<_> = rows.into_iter().collect();
+    let indices: HashMap<_, _> = rows
+        .iter()
+        .enumerate()
+        .map(|(i, row)| (key(row), i))
+        .collect();
+
+    let mut changes = vec![Some(Change::Added); rows.len()];
+    let mut removed: Vec<Vec<T>> = vec![Vec::new(); rows.len() + 1];
+    let mut anchor = 0;
+
+    // Removed rows stay after the last baseline row that still exists
+    for row in baseline {
+        match indices.get(&key(&row)) {
+            Some(&i) => {
+                changes[i] = (rows[i] != row).then_some(Change::Changed);
+                anchor = i + 1;
+            }
+            None => removed[anchor].push(row),
+        }
+    }
+
+    let mut merged = Vec::with_capacity(rows.len() + removed.iter().map(Vec::len).sum::<usize>());
+    let mut statuses = Vec::with_capacity(merged.capacity());
+
+    for (i, removed) in removed.into_iter().enumerate() {
+        if i > 0 {
+            merged.push(rows[i - 1].clone());
+            statuses.push(changes[i - 1]);
+        }
+
+        statuses.extend(std::iter::repeat_n(Some(Change::Removed), removed.len()));
+        merged.extend(removed);
+    }
+
+    let row_keys = merged.iter().map(|row| hash(key(row))).collect();
+
+    Table {
+        changes: statuses,
+        row_keys,
+        ..Table::new(columns, merged)
+    }
+}
+
+/// Groups the given columns under a header spanning all of them, shown in a
+/// tier above their own headers.
+///
+/// The resulting columns can be chained with others when creating a [`Table`].
+pub fn column_group<'a, 'b, T, Message, Theme, Renderer>(
+    header: impl Into<Element<'a, Message, Theme, Renderer>>,
+    columns: impl IntoIterator<Item = Column<'a, 'b, T, Message, Theme, Renderer>>,
+) -> Vec<Column<'a, 'b, T, Message, Theme, Renderer>> {
+    let group = Rc::new(RefCell::new(Some(header.into())));
+
+    columns
+        .into_iter()
+        .map(|column| Column {
+            group: Some(group.clone()),
+            ..column
+        })
+        .collect()
+}
+
 /// Creates a new [`Column`] with the given header and view function.
 ///
 /// The view function will be called for each row in a [`Table`] and it must
@@ -31,14 +231,187 @@ pub fn column<'a, 'b, T, E, Message, Theme, Renderer>(
 ) -> Column<'a, 'b, T, Message, Theme, Renderer>
 where
     T: 'a,
-    E: Into<Element<'a, Message, Theme, Renderer>>,
+    E: Into<Cell<'a, Message, Theme, Renderer>>,
+{
+    Column::new(header, Box::new(move |data, _| Some(view(data).into())))
+}
+
+/// Creates a new [`Column`] with the given header and a view function that
+/// may produce no contents.
+///
+/// Rows producing `None` are rendered according to the [`Missing`] policy
+/// of the [`Column`]. See [`Column::on_missing`].
+pub fn optional_column<'a, 'b, T, E, Message, Theme, Renderer>(
+    header: impl Into<Element<'a, Message, Theme, Renderer>>,
+    view: impl Fn(T) -> Option<E> + 'b,
+) -> Column<'a, 'b, T, Message, Theme, Renderer>
+where
+    T: 'a,
+    E: Into<Cell<'a, Message, Theme, Renderer>>,
+{
+    Column::new(header, Box::new(move |data, _| view(data).map(Into::into)))
+}
+
+/// Creates a new numeric [`Column`] with the given header and value function.
+///
+/// The values are displayed as right-aligned text. Their formatting can be
+/// configured with [`Column::decimals`], [`Column::auto_precision`] and
+/// [`Column::align_decimal`].
+pub fn numeric<'a, 'b, T, Message, Theme, Renderer>(
+    header: impl Into<Element<'a, Message, Theme, Renderer>>,
+    value: impl Fn(&T) -> f64 + 'b,
+) -> Column<'a, 'b, T, Message, Theme, Renderer>
+where
+    T: 'a,
+    Message: 'a,
+    Theme: widget::text::Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    let value = Rc::new(value);
+    let view = value.clone();
+
+    Column {
+        value: Some(Box::new(move |data| Value::Number(value(data)))),
+        align_x: alignment::Horizontal::Right,
+        ..Column::new(
+            header,
+            Box::new(move |data, format| Some(format.label(format.number(view(&data))).into())),
+        )
+    }
+}
+
+/// Creates a new [`Column`] with the given header and a function computing
+/// its [`Value`] from each row and the [`Context`] of the [`Table`].
+///
+/// Useful for derived values, like the percentage of a total, without
+/// storing them in the rows.
+pub fn computed_column<'a, 'b, T, Message, Theme, Renderer>(
+    header: impl Into<Element<'a, Message, Theme, Renderer>>,
+    value: impl Fn(&T, &Context) -> Value + 'b,
+) -> Column<'a, 'b, T, Message, Theme, Renderer>
+where
+    'a: 'b,
+    T: 'b,
+    Message: 'a,
+    Theme: widget::text::Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    let computed = Computed {
+        value: Rc::new(value),
+        context: Rc::default(),
+    };
+
+    let value = computed.value.clone();
+    let context = computed.context.clone();
+
+    Column {
+        computed: Some(computed),
+        ..Column::new(
+            header,
+            Box::new(move |data, format| {
+                let content = match value(&data, &context.borrow()) {
+                    Value::Number(number) => format.number(number),
+                    value => value.to_string(),
+                };
+
+                Some(format.label(content).into())
+            }),
+        )
+    }
+}
+
+/// Creates a new [`Column`] with the given header and [`CellRenderer`].
+///
+/// The cells of the [`Column`] are measured and drawn by the
+/// [`CellRenderer`], instead of building widgets for their contents. Every
+/// cell is still held by a small stateless widget of the [`Table`].
+pub fn custom_column<'a, 'b, T, Message, Theme, Renderer>(
+    header: impl Into<Element<'a, Message, Theme, Renderer>>,
+    cell_renderer: impl CellRenderer<T, Renderer> + 'a,
+) -> Column<'a, 'b, T, Message, Theme, Renderer>
+where
+    'a: 'b,
+    T: 'a,
+    Renderer: R + 'a,
+{
+    let cell_renderer: Rc<dyn CellRenderer<T, Renderer> + 'a> = Rc::new(cell_renderer);
+
+    Column::new(
+        header,
+        Box::new(move |data, _| {
+            Some(
+                Element::new(Rendered {
+                    data,
+                    cell_renderer: cell_renderer.clone(),
+                })
+                .into(),
+            )
+        }),
+    )
+}
+
+/// Creates a new [`Column`] with a grip that can be dragged to reorder the
+/// rows of a [`Table`].
+///
+/// Rows can only be dragged by their grip, so the other cells stay
+/// interactive. See [`Table::on_row_reorder`].
+pub fn drag_handle_column<'a, 'b, T, Message, Theme, Renderer>()
+-> Column<'a, 'b, T, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: R + 'a,
 {
     Column {
-        header: header.into(),
-        view: Box::new(move |data| view(data).into()),
-        width: Length::Shrink,
-        align_x: alignment::Horizontal::Left,
-        align_y: alignment::Vertical::Top,
+        handle: true,
+        align_y: alignment::Vertical::Center,
+        ..Column::new(
+            Space::new(),
+            Box::new(|_, _| Some(Element::new(Grip).into())),
+        )
+    }
+}
+
+/// Creates a view function for a [`Column`] whose cells are rebuilt
+/// periodically, at the given interval.
+///
+/// Useful for cells that depend on the current time, like relative
+/// timestamps, without having to rebuild the whole view.
+pub fn live_cell<'a, T, Message, Theme, Renderer>(
+    interval: Duration,
+    view: impl Fn(&T) -> Element<'a, Message, Theme, Renderer> + 'a,
+) -> impl Fn(T) -> Element<'a, Message, Theme, Renderer>
+where
+    T: 'a,
+    Message: 'a,
+    Theme: 'a,
+    Renderer: R + 'a,
+{
+    let view: LiveView<'a, T, Message, Theme, Renderer> = Rc::new(view);
+
+    move |row| {
+        Element::new(Live {
+            content: view(&row),
+            row,
+            view: view.clone(),
+            interval,
+        })
+    }
+}
+
+/// Creates a new [`Cell`] with the given content.
+///
+/// A [`Cell`] can be returned from the view function of a [`Column`] to
+/// override its alignment for a single row.
+pub fn cell<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Cell<'a, Message, Theme, Renderer> {
+    Cell {
+        content: content.into(),
+        align_x: None,
+        align_y: None,
+        span: 1,
+        row_span: 1,
     }
 }
 
@@ -49,13 +422,52 @@ where
 {
     columns: Vec<Column_>,
     cells: Vec<Element<'a, Message, Theme, Renderer>>,
+    alignments: Vec<(Option<alignment::Horizontal>, Option<alignment::Vertical>)>,
+    spans: Vec<(usize, usize)>,
+    changes: Vec<Option<Change>>,
+    baseline: bool,
+    presses: Vec<Option<Press<'a, Message>>>,
+    fingerprints: Vec<Option<u64>>,
+    row_keys: Vec<u64>,
+    flash: Option<(Duration, Color)>,
+    transition: Option<Duration>,
+    row_transition: Option<Duration>,
+    busy: bool,
+    shrink: bool,
+    export: Vec<Option<String>>,
     width: Length,
     height: Length,
     max_width: Length,
+    min_width: f32,
+    scale: f32,
+    labels: Scale,
     padding_x: f32,
     padding_y: f32,
     separator_x: f32,
     separator_y: f32,
+    id: Option<Id>,
+    gutter: Vec<Element<'a, Message, Theme, Renderer>>,
+    on_row_number_press: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_header_double_click: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_column_insert: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_column_remove: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    more: Option<Element<'a, Message, Theme, Renderer>>,
+    groups: Vec<(Range<usize>, Element<'a, Message, Theme, Renderer>)>,
+    hidden: usize,
+    on_show_more: Option<Box<dyn Fn() -> Message + 'a>>,
+    on_widths_reset: Option<Box<dyn Fn(Vec<f32>) -> Message + 'a>>,
+    resize: Option<ResizeMode>,
+    resize_step: Option<f32>,
+    on_column_resize: Option<Box<dyn Fn(usize, f32) -> Message + 'a>>,
+    on_row_resize: Option<Box<dyn Fn(usize, f32) -> Message + 'a>>,
+    on_row_reorder: Option<Box<dyn Fn(usize, usize) -> Message + 'a>>,
+    on_separator_click: Option<Box<dyn Fn(Separator) -> Message + 'a>>,
+    on_cell_drop: Option<Box<dyn Fn(RowColumn, RowColumn) -> Message + 'a>>,
+    on_separator_double_click: Option<Box<dyn Fn(Separator) -> Message + 'a>>,
+    min_row_height: f32,
+    max_row_height: f32,
+    accessibility: Accessibility,
+    scrollbar: Scrollbar,
     class: Theme::Class<'a>,
 }
 
@@ -63,6 +475,32 @@ struct Column_ {
     width: Length,
     align_x: alignment::Horizontal,
     align_y: alignment::Vertical,
+    header_align_x: Option<alignment::Horizontal>,
+    header_align_y: Option<alignment::Vertical>,
+    handle: bool,
+    hover: bool,
+    draggable: bool,
+    id: Option<u64>,
+    percent: Option<f32>,
+    weight: Option<f32>,
+    shrink_priority: u8,
+    fixed: Option<f32>,
+    source: usize,
+}
+
+impl Column_ {
+    /// Returns the identity of the column, kept across views.
+    fn key(&self) -> Key {
+        self.id.map_or(Key::Index(self.source), Key::Id)
+    }
+}
+
+/// The identity of a row or a column of a [`Table`]: a key given by the
+/// application, like a [`Column::id`], or its index as given on construction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Key {
+    Id(u64),
+    Index(usize),
 }
 
 impl<'a, Message, Theme, Renderer> Table<'a, Message, Theme, Renderer>
@@ -80,43 +518,220 @@ where
     ) -> Self
     where
         T: Clone,
+        Message: 'a,
+        Theme: 'a,
+        Renderer: 'a,
+    {
+        Self::with_baseline(columns, None, rows)
+    }
+
+    /// Creates a new [`Table`] whose first row is the given baseline, if any.
+    ///
+    /// The cells of the other rows in a column with a [`Column::delta`] are
+    /// the difference with the baseline.
+    fn with_baseline<'b, T>(
+        columns: impl IntoIterator<Item = Column<'a, 'b, T, Message, Theme, Renderer>>,
+        baseline: Option<T>,
+        rows: impl IntoIterator<Item = T>,
+    ) -> Self
+    where
+        T: Clone,
+        Message: 'a,
+        Theme: 'a,
+        Renderer: 'a,
     {
-        let columns = columns.into_iter();
-        let rows = rows.into_iter();
+        let columns: Vec<_> = columns.into_iter().collect();
+        let rows: Vec<_> = baseline.iter().cloned().chain(rows).collect();
+
+        // Computed columns see the values of the others, hidden or not
+        let context = Context::new(&columns, &rows);
+
+        // Columns keep their index as given on construction, hidden or not
+        let sources: Vec<_> = (0..columns.len())
+            .filter(|&source| !columns[source].hidden)
+            .collect();
+
+        let mut columns: Vec<_> = columns
+            .into_iter()
+            .filter(|column| !column.hidden)
+            .collect();
+
+        // Adjacent columns of the same group share its header
+        let mut groups = Vec::new();
+        let mut start = 0;
+
+        while start < columns.len() {
+            let Some(group) = columns[start].group.clone() else {
+                start += 1;
+                continue;
+            };
+
+            let span = columns[start..]
+                .iter()
+                .take_while(|column| column.group.as_ref().is_some_and(|g| Rc::ptr_eq(g, &group)))
+                .count();
+
+            if let Some(header) = group.borrow_mut().take() {
+                groups.push((start..start + span, header));
+            }
+
+            start += span;
+        }
 
         let mut width = Length::Shrink;
         let mut height = Length::Shrink;
 
-        let mut cells = Vec::with_capacity(columns.size_hint().0 * (1 + rows.size_hint().0));
+        // The text produced for the cells follows the scale of the table
+        let labels = Scale::default();
 
-        let (mut columns, views): (Vec<_>, Vec<_>) = columns
-            .map(|column| {
-                width = width.enclose(column.width);
+        for column in &mut columns {
+            if let Some(Computed {
+                value,
+                context: slot,
+            }) = column.computed.take()
+            {
+                *slot.borrow_mut() = context.clone();
+                column.value = Some(Box::new(move |row| value(row, &slot.borrow())));
+            }
+        }
 
-                cells.push(column.header);
+        for column in &mut columns {
+            width = width.enclose(column.width);
+            column.format.scale = labels.clone();
 
-                (
-                    Column_ {
-                        width: column.width,
-                        align_x: column.align_x,
-                        align_y: column.align_y,
-                    },
-                    column.view,
-                )
-            })
-            .collect();
+            if let Some(value) = &column.value {
+                column
+                    .format
+                    .resolve(rows.iter().map(value).filter_map(|value| value.number()));
+            }
+        }
+
+        // The copied text is laid out like the cells and joined on copy
+        let mut export = Vec::new();
+
+        if columns
+            .iter()
+            .any(|column| column.export.is_some() || column.value.is_some())
+        {
+            export.reserve(columns.len() * (1 + rows.len()));
+            export.extend(columns.iter().map(|column| {
+                match &column.export {
+                    Some((header, _)) => Some(header.clone()),
+                    None => column
+                        .value
+                        .as_ref()
+                        .map(|_| column.title.clone().unwrap_or_default()),
+                }
+            }));
+
+            for row in &rows {
+                export.extend(columns.iter().map(|column| match &column.export {
+                    Some((_, value)) => Some(value(row)),
+                    None => column.value.as_ref().map(|value| value(row).to_string()),
+                }));
+            }
+        }
+
+        // Presses are only stored when some column has them
+        let pressable = columns.iter().any(|column| column.on_press.is_some());
+
+        let mut cells = Vec::with_capacity(columns.len() * (1 + rows.len()));
+        let mut body = Vec::with_capacity(columns.len() * rows.len());
+        let mut alignments = Vec::with_capacity(columns.len() * rows.len());
+        let mut spans = Vec::with_capacity(columns.len() * rows.len());
+        let mut presses = Vec::with_capacity(if pressable {
+            columns.len() * rows.len()
+        } else {
+            0
+        });
+        let mut fingerprints = Vec::with_capacity(columns.len() * rows.len());
+
+        for (index, row) in rows.iter().enumerate() {
+            for column in &columns {
+                let view = match (&column.delta, &baseline) {
+                    (Some(delta), Some(baseline)) if index > 0 => Some(delta(row, baseline)),
+                    _ => (column.view)(row.clone(), &column.format),
+                };
 
-        for row in rows {
-            for view in &views {
-                let cell = view(row.clone());
-                let size_hint = cell.as_widget().size_hint();
+                let cell = view.unwrap_or_else(|| {
+                    cell(match &column.missing {
+                        Some(missing) => missing(&column.format),
+                        None => Space::new().into(),
+                    })
+                });
+                let size_hint = cell.content.as_widget().size_hint();
 
                 height = height.enclose(size_hint.height);
 
-                cells.push(cell);
+                body.push(cell.content);
+                alignments.push((cell.align_x, cell.align_y));
+                spans.push((cell.span, cell.row_span));
+                fingerprints.push(column.value.as_ref().map(|value| value(row).fingerprint()));
+
+                if pressable {
+                    presses.push(column.on_press.as_ref().map(|on_press| on_press(row)));
+                }
             }
         }
 
+        let mut columns: Vec<_> = columns
+            .into_iter()
+            .zip(sources)
+            .map(|(column, source)| {
+                let header = match column.summary {
+                    Some((aggregate, summary)) => {
+                        let values = column.value.as_ref().map_or_else(Vec::new, |value| {
+                            rows.iter()
+                                .map(value)
+                                .filter_map(|value| value.number())
+                                .collect()
+                        });
+
+                        summary(
+                            column.header,
+                            aggregate.apply(rows.len(), &values, &column.format),
+                            &labels,
+                            column.align_x,
+                        )
+                    }
+                    None => column.header,
+                };
+
+                let header = match column.statistics {
+                    Some(Statistics_ { value, tooltip }) => {
+                        let statistics = rows.iter().filter_map(value).collect();
+
+                        tooltip(header, statistics)
+                    }
+                    None => header,
+                };
+
+                cells.push(match column.header_tooltip {
+                    Some(tooltip) => tooltip(header),
+                    None => header,
+                });
+
+                Column_ {
+                    width: column.width,
+                    align_x: column.align_x,
+                    align_y: column.align_y,
+                    header_align_x: column.header_align_x,
+                    header_align_y: column.header_align_y,
+                    handle: column.handle,
+                    hover: column.hover,
+                    draggable: column.draggable,
+                    id: column.id,
+                    percent: column.percent,
+                    weight: column.weight,
+                    shrink_priority: column.shrink_priority,
+                    fixed: column.fixed,
+                    source,
+                }
+            })
+            .collect();
+
+        cells.extend(body);
+
         if width == Length::Shrink
             && let Some(first) = columns.first_mut()
         {
@@ -128,17 +743,62 @@ where
         Self {
             columns,
             cells,
+            alignments,
+            spans,
+            changes: Vec::new(),
+            baseline: false,
+            presses,
+            fingerprints,
+            row_keys: Vec::new(),
+            flash: None,
+            transition: None,
+            row_transition: None,
+            busy: false,
+            shrink: false,
+            export,
             width,
             max_width,
             height,
+            min_width: 0.0,
+            scale: 1.0,
+            labels,
             padding_x: 10.0,
             padding_y: 5.0,
             separator_x: 1.0,
             separator_y: 1.0,
+            id: None,
+            gutter: Vec::new(),
+            on_row_number_press: None,
+            on_header_double_click: None,
+            on_column_insert: None,
+            on_column_remove: None,
+            more: None,
+            groups,
+            hidden: 0,
+            on_show_more: None,
+            on_widths_reset: None,
+            resize: None,
+            resize_step: None,
+            on_column_resize: None,
+            on_row_resize: None,
+            on_row_reorder: None,
+            on_separator_click: None,
+            on_cell_drop: None,
+            on_separator_double_click: None,
+            min_row_height: 0.0,
+            max_row_height: f32::INFINITY,
+            accessibility: Accessibility::default(),
+            scrollbar: Scrollbar::default(),
             class: Theme::default(),
         }
     }
 
+    /// Sets the [`Id`] of the [`Table`].
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
     /// Sets the width of the [`Table`].
     pub fn width(mut self, width: impl Into<Length>) -> Self {
         self.width = width.into();
@@ -151,6 +811,25 @@ where
         self
     }
 
+    /// Sets the minimum width of the [`Table`].
+    ///
+    /// When the available space is narrower, the columns stop shrinking and the
+    /// [`Table`] scrolls horizontally instead.
+    pub fn min_width(mut self, width: impl Into<Pixels>) -> Self {
+        self.min_width = width.into().0;
+        self
+    }
+
+    /// Shrinks the columns in proportion to their widths when their contents
+    /// do not fit the available width, instead of overflowing.
+    ///
+    /// Columns do not shrink below the width of their header, nor below the
+    /// minimum width of the [`Table`]. See [`Column::shrink_priority`].
+    pub fn shrink_to_fit(mut self) -> Self {
+        self.shrink = true;
+        self
+    }
+
     /// Sets the padding of the cells of the [`Table`].
     pub fn padding(self, padding: impl Into<Pixels>) -> Self {
         let padding = padding.into();
@@ -160,13 +839,13 @@ where
 
     /// Sets the horizontal padding of the cells of the [`Table`].
     pub fn padding_x(mut self, padding: impl Into<Pixels>) -> Self {
-        self.padding_x = padding.into().0;
+        self.padding_x = padding.into().0 * self.scale;
         self
     }
 
     /// Sets the vertical padding of the cells of the [`Table`].
     pub fn padding_y(mut self, padding: impl Into<Pixels>) -> Self {
-        self.padding_y = padding.into().0;
+        self.padding_y = padding.into().0 * self.scale;
         self
     }
 
@@ -179,55 +858,4620 @@ where
 
     /// Sets the thickness of the horizontal line separator between the cells of the [`Table`].
     pub fn separator_x(mut self, separator: impl Into<Pixels>) -> Self {
-        self.separator_x = separator.into().0;
+        self.separator_x = separator.into().0 * self.scale;
         self
     }
 
     /// Sets the thickness of the vertical line separator between the cells of the [`Table`].
     pub fn separator_y(mut self, separator: impl Into<Pixels>) -> Self {
-        self.separator_y = separator.into().0;
+        self.separator_y = separator.into().0 * self.scale;
         self
     }
-}
 
-struct Metrics {
-    columns: Vec<f32>,
-    rows: Vec<f32>,
-}
+    /// Sets the scale factor of the [`Table`].
+    ///
+    /// Paddings, separators and the text produced by the [`Table`] itself
+    /// (row numbers, the "+ N more…" row, summaries and the values of
+    /// [`numeric`] and [`computed_column`] columns) are scaled accordingly.
+    /// Other contents of the cells are not affected.
+    ///
+    /// Factors that are not positive and finite are ignored.
+    pub fn scale(mut self, scale: f32) -> Self
+    where
+        Message: 'a,
+        Theme: widget::text::Catalog + 'a,
+        Renderer: text::Renderer + 'a,
+    {
+        if !(scale.is_finite() && scale > 0.0) {
+            return self;
+        }
 
-impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
-    for Table<'a, Message, Theme, Renderer>
-where
-    Theme: Catalog,
-    Renderer: R,
-{
-    fn size(&self) -> Size<Length> {
-        Size {
-            width: self.width,
-            height: self.height,
+        let factor = scale / self.scale;
+
+        self.scale = scale;
+        self.labels.0.set(scale);
+        self.padding_x *= factor;
+        self.padding_y *= factor;
+        self.separator_x *= factor;
+        self.separator_y *= factor;
+
+        if !self.gutter.is_empty() {
+            self = self.row_numbers(true);
         }
-    }
 
-    fn tag(&self) -> tree::Tag {
-        tree::Tag::of::<Metrics>()
-    }
+        if self.more.is_some() {
+            self.more = Some(self.text(format!("+ {} more\u{2026}", self.hidden)));
+        }
 
-    fn state(&self) -> tree::State {
-        tree::State::new(Metrics {
-            columns: Vec::new(),
-            rows: Vec::new(),
-        })
+        self
     }
 
-    fn children(&self) -> Vec<tree::Tree> {
-        self.cells
+    /// Sets whether the [`Table`] shows a gutter with the row numbers.
+    ///
+    /// The gutter is pinned to the left edge of the visible area when the
+    /// [`Table`] is scrolled horizontally.
+    pub fn row_numbers(mut self, enabled: bool) -> Self
+    where
+        Message: 'a,
+        Theme: widget::text::Catalog + 'a,
+        Renderer: text::Renderer + 'a,
+    {
+        self.gutter = if enabled {
+            let rows = self.cells.len() / self.columns.len().max(1);
+
+            (0..rows)
+                .map(|row| {
+                    if row == 0 {
+                        Space::new().into()
+                    } else {
+                        self.text(row)
+                    }
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        self
+    }
+
+    /// Sets the message that should be produced when the number of a row is
+    /// pressed in the gutter of the [`Table`].
+    ///
+    /// The index of the row is provided to the closure. See [`Table::row_numbers`].
+    pub fn on_row_number_press(mut self, on_press: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_row_number_press = Some(Box::new(on_press));
+        self
+    }
+
+    /// Sets the message that should be produced when the header of a column
+    /// is double-clicked.
+    ///
+    /// The index of the column, as given on construction, is provided to the
+    /// closure. Useful to rename columns inline, by replacing the header with
+    /// a `text_input` until the new name is submitted.
+    pub fn on_header_double_click(
+        mut self,
+        on_double_click: impl Fn(usize) -> Message + 'a,
+    ) -> Self {
+        self.on_header_double_click = Some(Box::new(on_double_click));
+        self
+    }
+
+    /// Sets the message that should be produced when the "+" affordance
+    /// shown between hovered headers is pressed.
+    ///
+    /// The index a new column should be inserted at, among the columns as
+    /// given on construction, is provided to the closure.
+    pub fn on_column_insert(mut self, on_insert: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_column_insert = Some(Box::new(on_insert));
+        self
+    }
+
+    /// Sets the message that should be produced when the "−" affordance
+    /// shown in a hovered header is pressed.
+    ///
+    /// The index of the column to remove, as given on construction, is
+    /// provided to the closure.
+    pub fn on_column_remove(mut self, on_remove: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_column_remove = Some(Box::new(on_remove));
+        self
+    }
+
+    /// Sets the message that should be produced when a row is dragged by its
+    /// grip and dropped somewhere else.
+    ///
+    /// The current index of the row and the index it should be moved to are
+    /// provided to the closure. See [`drag_handle_column`].
+    pub fn on_row_reorder(mut self, on_reorder: impl Fn(usize, usize) -> Message + 'a) -> Self {
+        self.on_row_reorder = Some(Box::new(on_reorder));
+        self
+    }
+
+    /// Sets the order in which the columns of the [`Table`] are displayed.
+    ///
+    /// Each entry is the index of a column as given on construction. Invalid,
+    /// hidden or repeated indices are ignored, and the columns left out keep
+    /// their relative order after the given ones.
+    pub fn column_order(mut self, order: &[usize]) -> Self {
+        let columns = self.columns.len();
+        let mut seen = vec![false; columns];
+
+        // Hidden columns are left out of the order
+        let positions = order.iter().filter_map(|&source| {
+            self.columns
+                .iter()
+                .position(|column| column.source == source)
+        });
+
+        let order: Vec<_> = positions
+            .chain(0..columns)
+            .filter(|&column| !std::mem::replace(&mut seen[column], true))
+            .collect();
+
+        self.columns = reorder(std::mem::take(&mut self.columns), &order);
+        self.cells = reorder(std::mem::take(&mut self.cells), &order);
+        self.alignments = reorder(std::mem::take(&mut self.alignments), &order);
+        self.spans = reorder(std::mem::take(&mut self.spans), &order);
+
+        if !self.presses.is_empty() {
+            self.presses = reorder(std::mem::take(&mut self.presses), &order);
+        }
+
+        self.fingerprints = reorder(std::mem::take(&mut self.fingerprints), &order);
+
+        if !self.export.is_empty() {
+            self.export = reorder(std::mem::take(&mut self.export), &order);
+        }
+
+        // Groups are kept as long as their columns stay together
+        self.groups = std::mem::take(&mut self.groups)
+            .into_iter()
+            .filter_map(|(range, header)| {
+                let mut positions: Vec<_> = range
+                    .map(|column| order.iter().position(|&new| new == column))
+                    .collect::<Option<_>>()?;
+
+                positions.sort_unstable();
+
+                let start = *positions.first()?;
+                let end = start + positions.len();
+
+                (positions.iter().copied().eq(start..end)).then_some((start..end, header))
+            })
+            .collect();
+        self
+    }
+
+    /// Restores the column widths saved in the given [`State`].
+    ///
+    /// Columns with a saved width keep it, like [`Column::fixed`]. Keep the
+    /// [`State`] up to date with [`Table::on_column_resize`] and
+    /// [`Table::on_widths_reset`].
+    ///
+    /// The widths are matched by the index of the columns as given on
+    /// construction, regardless of their order or visibility.
+    pub fn state(mut self, state: &State) -> Self {
+        for column in &mut self.columns {
+            if let Some(Some(width)) = state.widths.get(column.source) {
+                column.fixed = Some(*width);
+            }
+        }
+        self
+    }
+
+    /// Sets the [`Accessibility`] settings of the [`Table`].
+    pub fn accessibility(mut self, accessibility: Accessibility) -> Self {
+        self.accessibility = accessibility;
+        self
+    }
+
+    /// Sets the [`Scrollbar`] of the [`Table`], shown when the columns are
+    /// scrolled. See [`Table::min_width`].
+    pub fn scrollbar(mut self, scrollbar: Scrollbar) -> Self {
+        self.scrollbar = scrollbar;
+        self
+    }
+
+    /// Flashes the cells whose [`Value`] changed since the previous view with
+    /// the given color, fading out over the given duration.
+    ///
+    /// Only the columns with a [`Column::value`] are tracked. Cells are matched
+    /// by the keys of the rows of a [`compare`] table and by [`Column::id`],
+    /// falling back to the position of the rows and the index of the columns
+    /// as given on construction.
+    pub fn flash_changed(mut self, duration: Duration, color: impl Into<Color>) -> Self {
+        self.flash = Some((duration, color.into()));
+        self
+    }
+
+    /// Animates the widths of the columns over the given duration whenever
+    /// they change, instead of snapping to them.
+    ///
+    /// Columns follow the cursor without delay while their separators are
+    /// being dragged.
+    pub fn animate_widths(mut self, duration: Duration) -> Self {
+        self.transition = Some(duration);
+        self
+    }
+
+    /// Sets whether the [`Table`] is busy.
+    ///
+    /// A busy [`Table`] dims its body and shows a spinner over it, ignoring
+    /// any input until it is done.
+    pub fn busy(mut self, busy: bool) -> Self {
+        self.busy = busy;
+        self
+    }
+
+    /// Animates the heights of the rows over the given duration whenever
+    /// they change, instead of snapping to them.
+    ///
+    /// Useful when the contents of a row expand or collapse; the rows below
+    /// slide along, and cells are clipped to their row until it settles.
+    pub fn animate_heights(mut self, duration: Duration) -> Self {
+        self.row_transition = Some(duration);
+        self
+    }
+
+    /// Sets the maximum amount of rows shown by the [`Table`].
+    ///
+    /// The remaining rows are replaced by a single "+ N more…" row below the
+    /// last one. See [`Table::on_show_more`].
+    pub fn max_rows(mut self, rows: usize) -> Self
+    where
+        Message: 'a,
+        Theme: widget::text::Catalog + 'a,
+        Renderer: text::Renderer + 'a,
+    {
+        let columns = self.columns.len().max(1);
+        let hidden = (self.cells.len() / columns).saturating_sub(rows + 1);
+
+        if hidden == 0 {
+            return self;
+        }
+
+        self.cells.truncate(columns * (rows + 1));
+        self.alignments.truncate(columns * rows);
+        self.spans.truncate(columns * rows);
+        self.changes.truncate(rows);
+        self.presses.truncate(columns * rows);
+        self.fingerprints.truncate(columns * rows);
+        self.gutter.truncate(rows + 1);
+        self.hidden = hidden;
+        self.more = Some(self.text(format!("+ {hidden} more\u{2026}")));
+        self
+    }
+
+    /// Sets the message that should be produced when the "+ N more…" row of
+    /// the [`Table`] is pressed.
+    ///
+    /// See [`Table::max_rows`].
+    pub fn on_show_more(mut self, on_show_more: Message) -> Self
+    where
+        Message: Clone + 'a,
+    {
+        self.on_show_more = Some(Box::new(move || on_show_more.clone()));
+        self
+    }
+
+    /// Sets the message that should be produced when the widths of the columns
+    /// are reset with [`reset_widths`].
+    ///
+    /// The resulting widths of the columns are provided to the closure.
+    pub fn on_widths_reset(mut self, on_reset: impl Fn(Vec<f32>) -> Message + 'a) -> Self {
+        self.on_widths_reset = Some(Box::new(on_reset));
+        self
+    }
+
+    /// Sets the message that should be produced when a column of the [`Table`]
+    /// is resized by dragging its separator or by [`autofit`].
+    ///
+    /// The index of the column, as given on construction, and its new width
+    /// are provided to the closure, so they can be persisted and restored
+    /// later with [`Column::fixed`].
+    pub fn on_column_resize(mut self, on_resize: impl Fn(usize, f32) -> Message + 'a) -> Self {
+        self.on_column_resize = Some(Box::new(on_resize));
+        self
+    }
+
+    /// Sets the message that should be produced when a row of the [`Table`] is
+    /// resized by dragging its bottom separator.
+    ///
+    /// Rows become resizable when this handler is set. The index of the row and its
+    /// new height are provided to the closure.
+    pub fn on_row_resize(mut self, on_resize: impl Fn(usize, f32) -> Message + 'a) -> Self {
+        self.on_row_resize = Some(Box::new(on_resize));
+        self
+    }
+
+    /// Sets the message that should be produced when a [`Separator`] of the
+    /// [`Table`] is clicked.
+    ///
+    /// Separators are hit within the same zone used to resize them.
+    pub fn on_separator_click(mut self, on_click: impl Fn(Separator) -> Message + 'a) -> Self {
+        self.on_separator_click = Some(Box::new(on_click));
+        self
+    }
+
+    /// Sets the message that should be produced when a [`Separator`] of the
+    /// [`Table`] is double-clicked.
+    pub fn on_separator_double_click(
+        mut self,
+        on_double_click: impl Fn(Separator) -> Message + 'a,
+    ) -> Self {
+        self.on_separator_double_click = Some(Box::new(on_double_click));
+        self
+    }
+
+    /// Sets the message that should be produced when a cell of a draggable
+    /// [`Column`] is dropped on another one.
+    ///
+    /// The source and the target cells are provided to the closure. See
+    /// [`Column::draggable`].
+    ///
+    /// Cells can only be dropped within the [`Table`] they are dragged from;
+    /// releasing them over another [`Table`] cancels the drag.
+    pub fn on_cell_drop(mut self, on_drop: impl Fn(RowColumn, RowColumn) -> Message + 'a) -> Self {
+        self.on_cell_drop = Some(Box::new(on_drop));
+        self
+    }
+
+    /// Sets the minimum height of the rows resized by the user.
+    pub fn min_row_height(mut self, height: impl Into<Pixels>) -> Self {
+        self.min_row_height = height.into().0;
+        self
+    }
+
+    /// Sets the maximum height of the rows resized by the user.
+    pub fn max_row_height(mut self, height: impl Into<Pixels>) -> Self {
+        self.max_row_height = height.into().0;
+        self
+    }
+
+    /// Makes the columns of the [`Table`] resizable by dragging their separators,
+    /// using the given [`ResizeMode`].
+    pub fn resizable(mut self, mode: ResizeMode) -> Self {
+        self.resize = Some(mode);
+        self
+    }
+
+    /// Sets the step the widths of the columns are rounded to when resized by
+    /// the user or autofit.
+    ///
+    /// Keeps multiple tables visually aligned and avoids sub-pixel jitter.
+    pub fn resize_step(mut self, step: impl Into<Pixels>) -> Self {
+        self.resize_step = Some(step.into().0).filter(|step| *step > 0.0);
+        self
+    }
+
+    /// Sets the style of the [`Table`].
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Table`].
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn text(
+        &self,
+        content: impl widget::text::IntoFragment<'a>,
+    ) -> Element<'a, Message, Theme, Renderer>
+    where
+        Message: 'a,
+        Theme: widget::text::Catalog + 'a,
+        Renderer: text::Renderer + 'a,
+    {
+        let text = widget::text(content);
+
+        if self.scale == 1.0 {
+            text.into()
+        } else {
+            text.size(TEXT_SIZE * self.scale).into()
+        }
+    }
+
+    /// Joins the exported columns of the [`Table`] as tab-separated values.
+    fn tsv(&self) -> String {
+        let columns = self.columns.len();
+        let exported: Vec<_> = (0..columns)
+            .filter(|&column| self.export[column].is_some())
+            .collect();
+
+        self.export
+            .chunks(columns)
+            .map(|row| {
+                exported
+                    .iter()
+                    .map(|&column| sanitize(row[column].as_deref().unwrap_or_default()))
+                    .collect::<Vec<_>>()
+                    .join("\t")
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn snap(&self, width: f32) -> f32 {
+        match self.resize_step {
+            Some(step) => (width / step).round() * step,
+            None => width,
+        }
+    }
+
+    fn appearance(&self, theme: &Theme, defaults: &renderer::Style) -> Style {
+        appearance(theme, &self.class, self.accessibility, defaults)
+    }
+
+    fn config(&self) -> Config {
+        Config {
+            padding_x: self.padding_x,
+            padding_y: self.padding_y,
+            separator_x: self.separator_x,
+            separator_y: self.separator_y,
+            min_width: self.min_width,
+            resize_step: self.resize_step,
+            shrink: self.shrink,
+        }
+    }
+
+    fn column_x(&self, metrics: &Metrics, column: usize) -> f32 {
+        let spacing_x = self.padding_x * 2.0 + self.separator_x;
+
+        metrics.gutter
+            + self.padding_x
+            + metrics.columns[..column]
+                .iter()
+                .map(|width| width + spacing_x)
+                .sum::<f32>()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn draw_content(
+        &self,
+        tree: &tree::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let metrics = tree.state.downcast_ref::<Metrics>();
+        let appearance = self.appearance(theme, style);
+
+        let defaults = &renderer::Style {
+            text_color: appearance.text_color.unwrap_or(style.text_color),
+        };
+        let style = appearance;
+
+        if let Some(background) = style.background {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        width: bounds.width.max(metrics.content_width),
+                        ..bounds
+                    },
+                    snap: true,
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+        }
+
+        if let Some((row, background)) = metrics
+            .hovered_row
+            .filter(|row| *row < metrics.rows.len())
+            .zip(style.row_hovered)
+        {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: bounds.x + metrics.gutter,
+                        y: bounds.y + self.row_y(metrics, row) - self.padding_y,
+                        width: bounds.width.max(metrics.content_width) - metrics.gutter,
+                        height: metrics.rows[row] + self.padding_y * 2.0,
+                    },
+                    snap: true,
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+        }
+
+        // Only what intersects the viewport is drawn
+        let spacing_y = self.padding_y * 2.0 + self.separator_y;
+        let mut y = metrics
+            .rows
+            .first()
+            .map_or(0.0, |header| header + spacing_y);
+
+        if self.baseline
+            && let Some(height) = metrics.rows.get(1)
+        {
+            let row = Rectangle {
+                x: bounds.x + metrics.gutter,
+                y: bounds.y + y,
+                width: bounds.width.max(metrics.content_width) - metrics.gutter,
+                height: height + self.padding_y * 2.0,
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: row,
+                    snap: true,
+                    ..renderer::Quad::default()
+                },
+                style.baseline,
+            );
+        }
+
+        // The header is the first row of the metrics
+        for (change, height) in self.changes.iter().zip(metrics.rows.iter().skip(1)) {
+            let row = Rectangle {
+                x: bounds.x + metrics.gutter,
+                y: bounds.y + y,
+                width: bounds.width.max(metrics.content_width) - metrics.gutter,
+                height: height + self.padding_y * 2.0,
+            };
+
+            y += height + spacing_y;
+
+            let Some(change) = change else {
+                continue;
+            };
+
+            if !row.intersects(viewport) {
+                continue;
+            }
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: row,
+                    snap: true,
+                    ..renderer::Quad::default()
+                },
+                match change {
+                    Change::Added => style.added,
+                    Change::Removed => style.removed,
+                    Change::Changed => style.changed,
+                },
+            );
+        }
+
+        if let Some(cell) = metrics.pressed {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: self.cell_bounds(metrics, bounds, cell),
+                    snap: true,
+                    ..renderer::Quad::default()
+                },
+                style.pressed,
+            );
+        }
+
+        if let Some(CellDrag {
+            source,
+            target: Some(target),
+        }) = metrics.cell_drag
+            && target != source
+        {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: self.cell_bounds(metrics, bounds, target),
+                    snap: true,
+                    ..renderer::Quad::default()
+                },
+                style.drop_target,
+            );
+        }
+
+        if let Some((duration, color)) = self.flash
+            && let Some(now) = metrics.now
+        {
+            for (&cell, &start) in &metrics.flashes {
+                if cell / self.columns.len() + 1 >= metrics.rows.len() {
+                    continue;
+                }
+
+                let progress = now.saturating_duration_since(start).as_secs_f32()
+                    / duration.as_secs_f32().max(f32::EPSILON);
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: self.cell_bounds(metrics, bounds, cell),
+                        snap: true,
+                        ..renderer::Quad::default()
+                    },
+                    color.scale_alpha((1.0 - progress).clamp(0.0, 1.0)),
+                );
+            }
+        }
+
+        for (i, ((cell, state), layout)) in self
+            .cells
             .iter()
-            .map(|cell| tree::Tree::new(cell.as_widget()))
+            .zip(&tree.children)
+            .zip(layout.children())
+            .chain(
+                self.groups
+                    .iter()
+                    .map(|(_, header)| header)
+                    .zip(&tree.children[self.cells.len() + self.gutter.len()..])
+                    .zip(layout.children().skip(self.cells.len() + self.gutter.len())),
+            )
+            .chain(
+                self.more
+                    .iter()
+                    .zip(tree.children.last())
+                    .zip(layout.children().last()),
+            )
+            .enumerate()
+        {
+            if !layout.bounds().intersects(viewport) || self.conceals(metrics, bounds, cursor, i) {
+                continue;
+            }
+
+            // Cells overflow their rows while the heights are animated
+            if metrics.heights.is_running() && i < self.cells.len() {
+                let row = i / self.columns.len();
+                let clip = Rectangle {
+                    y: bounds.y + self.row_y(metrics, row),
+                    height: self.span_size(metrics, i).height,
+                    ..layout.bounds()
+                };
+
+                if let Some(clip) = clip.intersection(viewport) {
+                    renderer.with_layer(clip, |renderer| {
+                        cell.as_widget()
+                            .draw(state, renderer, theme, defaults, layout, cursor, &clip);
+                    });
+                }
+
+                continue;
+            }
+
+            cell.as_widget()
+                .draw(state, renderer, theme, defaults, layout, cursor, viewport);
+        }
+
+        let origin = Vector::new(bounds.x, bounds.y);
+
+        for &(column, header, separator) in &metrics.separators.columns {
+            let Some(separator) = (separator + origin).intersection(viewport) else {
+                continue;
+            };
+
+            // The separators around the hovered column may be highlighted
+            let color = match style.separator_hovered {
+                _ if header => style.separator_header_x,
+                Some(hovered)
+                    if metrics
+                        .hovered_column
+                        .is_some_and(|hovered| hovered == column || hovered == column + 1) =>
+                {
+                    hovered
+                }
+                _ => style.separator_x,
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: separator,
+                    snap: true,
+                    ..renderer::Quad::default()
+                },
+                color,
+            );
+        }
+
+        // Group headers are separated from the headers of their columns
+        if self.separator_y > 0.0 {
+            for (range, _) in &self.groups {
+                let x = self.column_x(metrics, range.start) - self.padding_x;
+                let end = range.end - 1;
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: bounds.x + x,
+                            y: bounds.y + metrics.tier - self.separator_y,
+                            width: self.column_x(metrics, end)
+                                + metrics.columns[end]
+                                + self.padding_x
+                                - x,
+                            height: self.separator_y,
+                        },
+                        snap: true,
+                        ..renderer::Quad::default()
+                    },
+                    style.separator_header_y,
+                );
+            }
+        }
+
+        // The row separators are sorted, so only the visible ones are walked
+        let rows = &metrics.separators.rows;
+        let first = rows.partition_point(|(_, separator)| {
+            bounds.y + separator.y + separator.height < viewport.y
+        });
+
+        for &(row, separator) in &rows[first..] {
+            let separator = separator + origin;
+
+            if separator.y > viewport.y + viewport.height {
+                break;
+            }
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: separator,
+                    snap: true,
+                    ..renderer::Quad::default()
+                },
+                if row == 0 {
+                    style.separator_header_y
+                } else {
+                    style.separator_y
+                },
+            );
+        }
+
+        if let Some(position) = cursor.position_in(bounds) {
+            for (affordance, badge) in self.affordances(metrics, position) {
+                let badge = badge + Vector::new(bounds.x, bounds.y);
+                let stroke = (AFFORDANCE / 7.0).round();
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: badge,
+                        border: border::rounded(AFFORDANCE / 2.0),
+                        ..renderer::Quad::default()
+                    },
+                    style.affordance,
+                );
+
+                let bar = |width: f32, height: f32| Rectangle {
+                    x: badge.center_x() - width / 2.0,
+                    y: badge.center_y() - height / 2.0,
+                    width,
+                    height,
+                };
+
+                let size = AFFORDANCE / 2.0;
+                let mut bars = vec![bar(size, stroke)];
+
+                if let Affordance::Insert(_) = affordance {
+                    bars.push(bar(stroke, size));
+                }
+
+                for bar in bars {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: bar,
+                            snap: true,
+                            ..renderer::Quad::default()
+                        },
+                        style.affordance_icon,
+                    );
+                }
+            }
+        }
+
+        if let Some(reorder) = &metrics.reorder {
+            let y = self.row_y(metrics, reorder.target + 1) - self.padding_y;
+            let height = self.separator_y.max(2.0);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        y: bounds.y + y - (self.separator_y + height) / 2.0,
+                        height,
+                        ..bounds
+                    },
+                    snap: true,
+                    ..renderer::Quad::default()
+                },
+                defaults.text_color,
+            );
+        }
+
+        if !self.gutter.is_empty() {
+            let offset = self.gutter_offset(metrics, bounds, viewport);
+            let gutter = Rectangle {
+                x: bounds.x + offset,
+                width: metrics.gutter,
+                ..bounds
+            };
+
+            renderer.with_layer(gutter, |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: gutter,
+                        snap: true,
+                        ..renderer::Quad::default()
+                    },
+                    style.gutter,
+                );
+
+                renderer.with_translation(Vector::new(offset, 0.0), |renderer| {
+                    for ((number, state), layout) in self
+                        .gutter
+                        .iter()
+                        .zip(&tree.children[self.cells.len()..])
+                        .zip(layout.children().skip(self.cells.len()))
+                    {
+                        number
+                            .as_widget()
+                            .draw(state, renderer, theme, defaults, layout, cursor, viewport);
+                    }
+                });
+
+                if self.separator_x > 0.0 {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: gutter.x + gutter.width - self.separator_x,
+                                width: self.separator_x,
+                                ..gutter
+                            },
+                            snap: true,
+                            ..renderer::Quad::default()
+                        },
+                        style.separator_x,
+                    );
+                }
+            });
+        }
+    }
+
+    /// Dims the visible body of a busy [`Table`] and draws a spinner over it.
+    fn draw_busy(
+        &self,
+        metrics: &Metrics,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        bounds: Rectangle,
+        viewport: &Rectangle,
+    ) {
+        if !self.busy {
+            return;
+        }
+
+        let header = metrics.rows.first().map_or(0.0, |height| {
+            height + self.padding_y * 2.0 + self.separator_y
+        });
+
+        let body = Rectangle {
+            y: bounds.y + header,
+            height: (bounds.height - header).max(0.0),
+            ..bounds
+        };
+
+        let Some(visible) = body.intersection(viewport) else {
+            return;
+        };
+
+        let appearance = self.appearance(theme, style);
+
+        renderer.with_layer(visible, |renderer| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: visible,
+                    ..renderer::Quad::default()
+                },
+                appearance.busy,
+            );
+
+            // The dots fade out behind the leading one
+            let elapsed = metrics.busy.zip(metrics.now).map_or(0.0, |(start, now)| {
+                now.saturating_duration_since(start).as_secs_f32()
+            });
+
+            let leading = if self.accessibility.reduced_motion {
+                0.0
+            } else {
+                (elapsed * SPINNER_DOTS as f32).floor()
+            };
+
+            let center = visible.center();
+
+            for dot in 0..SPINNER_DOTS {
+                let angle = std::f32::consts::TAU * dot as f32 / SPINNER_DOTS as f32;
+                let age = (leading - dot as f32).rem_euclid(SPINNER_DOTS as f32);
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: center.x + SPINNER_RADIUS * angle.cos() - SPINNER_DOT / 2.0,
+                            y: center.y + SPINNER_RADIUS * angle.sin() - SPINNER_DOT / 2.0,
+                            width: SPINNER_DOT,
+                            height: SPINNER_DOT,
+                        },
+                        border: border::rounded(SPINNER_DOT / 2.0),
+                        ..renderer::Quad::default()
+                    },
+                    appearance
+                        .spinner
+                        .scale_alpha(1.0 - age / SPINNER_DOTS as f32),
+                );
+            }
+        });
+    }
+
+    fn scrolls(&self, metrics: &Metrics, bounds: Rectangle) -> bool {
+        self.min_width > 0.0 && metrics.content_width > bounds.width
+    }
+
+    /// Returns the track and the scroller of the horizontal scrollbar, kept
+    /// at the bottom of the visible part of the [`Table`].
+    fn scroller(
+        &self,
+        metrics: &Metrics,
+        bounds: Rectangle,
+        viewport: &Rectangle,
+    ) -> Option<(Rectangle, Rectangle)> {
+        if !self.scrolls(metrics, bounds) {
+            return None;
+        }
+
+        let Scrollbar { width, margin, .. } = self.scrollbar;
+        let bottom = (bounds.y + bounds.height).min(viewport.y + viewport.height);
+
+        let track = Rectangle {
+            x: bounds.x + margin,
+            y: (bottom - width - margin).max(bounds.y),
+            width: (bounds.width - margin * 2.0).max(0.0),
+            height: width,
+        };
+
+        let width = (track.width * bounds.width / metrics.content_width).max(width * 2.0);
+        let offset = metrics.scroll_x / (metrics.content_width - bounds.width);
+
+        let scroller = Rectangle {
+            x: track.x + (track.width - width) * offset,
+            width,
+            ..track
+        };
+
+        Some((track, scroller))
+    }
+
+    fn scroll_to(&self, metrics: &mut Metrics, bounds: Rectangle, scroll_x: f32) -> bool {
+        let scroll_x = scroll_x.clamp(0.0, (metrics.content_width - bounds.width).max(0.0));
+        let changed = scroll_x != metrics.scroll_x;
+
+        metrics.scroll_x = scroll_x;
+        changed
+    }
+
+    /// Returns the position of the cursor relative to the contents of the [`Table`].
+    fn position(
+        &self,
+        metrics: &Metrics,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> Option<Point> {
+        let position = cursor.position_in(bounds)?;
+
+        Some(Point::new(position.x + metrics.scroll_x, position.y))
+    }
+
+    /// Returns the cursor as seen by the cells of the [`Table`].
+    fn content_cursor(
+        &self,
+        metrics: &Metrics,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> mouse::Cursor {
+        if !self.scrolls(metrics, bounds) {
+            cursor
+        } else if cursor.is_over(bounds) {
+            cursor + Vector::new(metrics.scroll_x, 0.0)
+        } else {
+            mouse::Cursor::Unavailable
+        }
+    }
+
+    /// Returns the ghost line of a deferred resize, relative to the contents,
+    /// and the kind of boundary being dragged.
+    fn ghost(&self, metrics: &Metrics, bounds: Size) -> Option<(Rectangle, Boundary)> {
+        if self.resize.unwrap_or_default() != ResizeMode::Deferred {
+            return None;
+        }
+
+        let drag = metrics.drag.as_ref()?;
+        let bounds = Rectangle::with_size(Size::new(
+            bounds.width.max(metrics.content_width),
+            bounds.height,
+        ));
+
+        let ghost = match drag.boundary {
+            Boundary::Column(column) => Rectangle {
+                x: self.column_x(metrics, column) + drag.current + self.padding_x,
+                width: self.separator_x.max(2.0),
+                ..bounds
+            },
+            Boundary::Row(row) => Rectangle {
+                y: self.row_y(metrics, row) + drag.current + self.padding_y,
+                height: self.separator_y.max(2.0),
+                ..bounds
+            },
+        };
+
+        Some((ghost, drag.boundary))
+    }
+
+    fn row_y(&self, metrics: &Metrics, row: usize) -> f32 {
+        let spacing_y = self.padding_y * 2.0 + self.separator_y;
+
+        self.padding_y
+            + metrics.rows[..row]
+                .iter()
+                .map(|height| height + spacing_y)
+                .sum::<f32>()
+    }
+
+    /// Returns the boundary that can be resized at the given position, if any.
+    fn boundary_at(&self, metrics: &Metrics, position: Point) -> Option<Boundary> {
+        self.separator_at(metrics, position)
+            .filter(|boundary| match boundary {
+                Boundary::Column(_) => self.resize.is_some(),
+                Boundary::Row(_) => self.on_row_resize.is_some(),
+            })
+    }
+
+    /// Returns the boundary whose separator is at the given position, if any.
+    fn separator_at(&self, metrics: &Metrics, position: Point) -> Option<Boundary> {
+        {
+            let mut right = metrics.gutter + self.padding_x;
+
+            for (column, width) in metrics.columns.iter().enumerate() {
+                right += width + self.padding_x;
+
+                if (position.x - right - self.separator_x / 2.0).abs() <= RESIZE_HANDLE {
+                    return Some(Boundary::Column(column));
+                }
+
+                right += self.separator_x + self.padding_x;
+            }
+        }
+
+        {
+            let mut bottom = self.padding_y;
+
+            for (row, height) in metrics.rows.iter().enumerate() {
+                bottom += height + self.padding_y;
+
+                // The header keeps its measured height
+                if row > 0 && (position.y - bottom - self.separator_y / 2.0).abs() <= RESIZE_HANDLE
+                {
+                    return Some(Boundary::Row(row));
+                }
+
+                bottom += self.separator_y + self.padding_y;
+            }
+        }
+
+        None
+    }
+
+    /// Returns whether the body cell is hidden, since its [`Column`] is only
+    /// shown on the hovered row. Takes the cursor as seen by the cells.
+    fn conceals(
+        &self,
+        metrics: &Metrics,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+        cell: usize,
+    ) -> bool {
+        let columns = self.columns.len();
+
+        if metrics.owners.get(cell).is_some_and(|owner| *owner != cell) {
+            return true;
+        }
+
+        if cell < columns || cell >= self.cells.len() || !self.columns[cell % columns].hover {
+            return false;
+        }
+
+        let content = Rectangle {
+            width: bounds.width.max(metrics.content_width),
+            ..bounds
+        };
+
+        cursor
+            .position_over(content)
+            .and_then(|position| self.row_at(metrics, position.y - bounds.y))
+            != Some(cell / columns)
+    }
+
+    /// Returns the amount of columns and rows spanned by every cell, and the
+    /// index of the cell shown in place of each one.
+    ///
+    /// Spans stop at the cells already covered by a previous one.
+    fn owners(&self) -> (Vec<(usize, usize)>, Vec<usize>) {
+        let columns = self.columns.len();
+        let rows = self.cells.len() / columns;
+
+        let mut spans = vec![(1, 1); self.cells.len()];
+        let mut owners: Vec<_> = (0..self.cells.len()).collect();
+
+        for i in columns..self.cells.len() {
+            if owners[i] != i {
+                continue;
+            }
+
+            let (row, column) = (i / columns, i % columns);
+            let (span_x, span_y) = self.spans.get(i - columns).copied().unwrap_or((1, 1));
+
+            let span_x = (0..span_x.min(columns - column))
+                .take_while(|offset| owners[i + offset] == i + offset)
+                .count();
+
+            let span_y = (0..span_y.min(rows - row))
+                .take_while(|offset| {
+                    let start = i + offset * columns;
+
+                    (start..start + span_x).all(|cell| owners[cell] == cell)
+                })
+                .count();
+
+            spans[i] = (span_x, span_y);
+
+            for row in row..row + span_y {
+                for column in column..column + span_x {
+                    owners[row * columns + column] = i;
+                }
+            }
+        }
+
+        (spans, owners)
+    }
+
+    /// Returns whether the given cells are part of the same spanning cell.
+    fn merged(metrics: &Metrics, a: usize, b: usize) -> bool {
+        metrics
+            .owners
+            .get(a)
+            .is_some_and(|owner| metrics.owners.get(b) == Some(owner))
+    }
+
+    /// Returns the size of the area spanned by the cell at the given index.
+    fn span_size(&self, metrics: &Metrics, cell: usize) -> Size {
+        let columns = self.columns.len();
+        let (row, column) = (cell / columns, cell % columns);
+        let (span_x, span_y) = metrics.spans.get(cell).copied().unwrap_or((1, 1));
+
+        let spacing_x = self.padding_x * 2.0 + self.separator_x;
+        let spacing_y = self.padding_y * 2.0 + self.separator_y;
+
+        Size::new(
+            metrics.columns[column..column + span_x].iter().sum::<f32>()
+                + spacing_x * (span_x - 1) as f32,
+            metrics.rows[row..row + span_y].iter().sum::<f32>() + spacing_y * (span_y - 1) as f32,
+        )
+    }
+
+    fn row_at(&self, metrics: &Metrics, y: f32) -> Option<usize> {
+        let mut top = 0.0;
+
+        for (row, height) in metrics.rows.iter().enumerate() {
+            let bottom = top + height + self.padding_y * 2.0 + self.separator_y;
+
+            if y >= top && y < bottom {
+                return Some(row);
+            }
+
+            top = bottom;
+        }
+
+        None
+    }
+
+    /// Returns the affordances of the header under the given position,
+    /// relative to the contents, and their bounds.
+    fn affordances(&self, metrics: &Metrics, position: Point) -> Vec<(Affordance, Rectangle)> {
+        if self.row_at(metrics, position.y) != Some(0) {
+            return Vec::new();
+        }
+
+        let Some(column) = self.column_at(metrics, position.x) else {
+            return Vec::new();
+        };
+
+        let left = self.column_x(metrics, column);
+        let right = left + metrics.columns[column];
+        let half = self.padding_x + self.separator_x / 2.0;
+        let y = metrics.tier + self.padding_y + (metrics.rows[0] - metrics.tier) / 2.0;
+
+        let badge = |x: f32| Rectangle {
+            x: x - AFFORDANCE / 2.0,
+            y: y - AFFORDANCE / 2.0,
+            width: AFFORDANCE,
+            height: AFFORDANCE,
+        };
+
+        let mut affordances = Vec::new();
+
+        // Affordances refer to the columns as given on construction
+        let source = self.columns[column].source;
+
+        if self.on_column_insert.is_some() {
+            affordances.push((Affordance::Insert(source), badge(left - half)));
+            affordances.push((Affordance::Insert(source + 1), badge(right + half)));
+        }
+
+        if self.on_column_remove.is_some() {
+            affordances.push((Affordance::Remove(source), badge(right - AFFORDANCE / 2.0)));
+        }
+
+        affordances
+    }
+
+    fn affordance_at(&self, metrics: &Metrics, position: Point) -> Option<Affordance> {
+        self.affordances(metrics, position)
+            .into_iter()
+            .find(|(_, bounds)| bounds.contains(position))
+            .map(|(affordance, _)| affordance)
+    }
+
+    fn column_at(&self, metrics: &Metrics, x: f32) -> Option<usize> {
+        let mut left = metrics.gutter;
+
+        for (column, width) in metrics.columns.iter().enumerate() {
+            let right = left + width + self.padding_x * 2.0 + self.separator_x;
+
+            if x >= left && x < right {
+                return Some(column);
+            }
+
+            left = right;
+        }
+
+        None
+    }
+
+    /// Returns the index of the pressable body cell under the cursor, if any.
+    fn press_at(
+        &self,
+        metrics: &Metrics,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> Option<usize> {
+        if self.presses.is_empty() {
+            return None;
+        }
+
+        let position = self.position(metrics, bounds, cursor)?;
+        let column = self.column_at(metrics, position.x)?;
+        let row = self.row_at(metrics, position.y)?.checked_sub(1)?;
+        let cell = row * self.columns.len() + column;
+
+        self.presses.get(cell)?.as_ref().map(|_| cell)
+    }
+
+    /// Returns the index of the draggable body cell under the cursor, if any.
+    fn draggable_at(
+        &self,
+        metrics: &Metrics,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> Option<usize> {
+        let position = self.position(metrics, bounds, cursor)?;
+        let column = self.column_at(metrics, position.x)?;
+        let row = self.row_at(metrics, position.y)?.checked_sub(1)?;
+
+        self.columns[column]
+            .draggable
+            .then_some(row * self.columns.len() + column)
+    }
+
+    /// Returns the bounds of the body cell, including its padding.
+    fn cell_bounds(&self, metrics: &Metrics, bounds: Rectangle, cell: usize) -> Rectangle {
+        let row = cell / self.columns.len() + 1;
+        let column = cell % self.columns.len();
+
+        Rectangle {
+            x: bounds.x + self.column_x(metrics, column) - self.padding_x,
+            y: bounds.y + self.row_y(metrics, row) - self.padding_y,
+            width: metrics.columns[column] + self.padding_x * 2.0,
+            height: metrics.rows[row] + self.padding_y * 2.0,
+        }
+    }
+
+    /// Returns the row whose grip is under the cursor, if any.
+    fn handle_at(
+        &self,
+        metrics: &Metrics,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> Option<usize> {
+        let position = self.position(metrics, bounds, cursor)?;
+        let column = self.column_at(metrics, position.x)?;
+
+        if !self.columns[column].handle {
+            return None;
+        }
+
+        self.row_at(metrics, position.y)?.checked_sub(1)
+    }
+
+    /// Returns the index where the dragged row would be inserted.
+    fn drop_target(&self, metrics: &Metrics, y: f32) -> usize {
+        let rows = metrics.rows.len().saturating_sub(1);
+
+        (1..=rows)
+            .find(|&row| y < self.row_y(metrics, row) + metrics.rows[row] / 2.0)
+            .map_or(rows, |row| row - 1)
+    }
+
+    fn row_number_at(
+        &self,
+        tree: &tree::Tree,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) -> Option<usize> {
+        let metrics = tree.state.downcast_ref::<Metrics>();
+        let position = self.position(metrics, bounds, cursor)?;
+        let offset = self.gutter_offset(metrics, bounds, viewport);
+
+        if position.x < offset || position.x >= offset + metrics.gutter {
+            return None;
+        }
+
+        self.row_at(metrics, position.y)?.checked_sub(1)
+    }
+
+    fn more_at(&self, metrics: &Metrics, bounds: Rectangle, cursor: mouse::Cursor) -> bool {
+        self.more.is_some()
+            && self
+                .position(metrics, bounds, cursor)
+                .is_some_and(|position| {
+                    position.y >= self.row_y(metrics, metrics.rows.len()) - self.padding_y
+                })
+    }
+
+    fn gutter_offset(&self, metrics: &Metrics, bounds: Rectangle, viewport: &Rectangle) -> f32 {
+        ((viewport.x - bounds.x).max(0.0) + metrics.scroll_x)
+            .min((bounds.width.max(metrics.content_width) - metrics.gutter).max(0.0))
+    }
+
+    /// Returns the keys of the row and the column of every body cell, if
+    /// changes are flashed.
+    fn cell_keys(&self) -> Vec<(Key, Key)> {
+        if self.flash.is_none() {
+            return Vec::new();
+        }
+
+        let columns = self.columns.len();
+
+        (0..self.fingerprints.len())
+            .map(|cell| {
+                let row = cell / columns;
+                let key = self
+                    .row_keys
+                    .get(row)
+                    .map_or(Key::Index(row), |&key| Key::Id(key));
+
+                (key, self.columns[cell % columns].key())
+            })
+            .collect()
+    }
+
+    /// Returns the fingerprints of the values of the cells with the given keys.
+    fn fingerprints(&self, keys: &[(Key, Key)]) -> HashMap<(Key, Key), u64> {
+        keys.iter()
+            .zip(&self.fingerprints)
+            .filter_map(|(key, fingerprint)| Some((*key, (*fingerprint)?)))
+            .collect()
+    }
+
+    /// Splits the separators of the laid out [`Table`] of the given size
+    /// around the cells spanning them.
+    fn separators(&self, metrics: &Metrics, size: Size) -> Separators {
+        let mut separators = Separators::default();
+        let columns = metrics.columns.len();
+
+        if self.separator_x > 0.0 {
+            let mut x = metrics.gutter + self.padding_x;
+
+            // The header has its own separators, down to its bottom separator
+            let header = metrics
+                .rows
+                .first()
+                .map_or(0.0, |height| height + self.padding_y * 2.0);
+
+            for (column, width) in metrics.columns[..columns.saturating_sub(1)]
+                .iter()
+                .enumerate()
+            {
+                x += width + self.padding_x;
+
+                let separator = Rectangle {
+                    x,
+                    y: 0.0,
+                    width: self.separator_x,
+                    height: size.height,
+                };
+
+                x += self.separator_x + self.padding_x;
+
+                // Separators within a group start below its header
+                let top = if self
+                    .groups
+                    .iter()
+                    .any(|(range, _)| range.start <= column && column + 1 < range.end)
+                {
+                    metrics.tier
+                } else {
+                    0.0
+                };
+
+                separators.columns.push((
+                    column,
+                    true,
+                    Rectangle {
+                        y: top,
+                        height: (header - top).clamp(0.0, separator.height),
+                        ..separator
+                    },
+                ));
+
+                // The body separator is interrupted by the cells spanning it
+                let mut start = header;
+
+                for row in 1..metrics.rows.len() {
+                    let cell = row * columns + column;
+
+                    if Self::merged(metrics, cell, cell + 1) {
+                        let end = self.row_y(metrics, row) - self.padding_y;
+
+                        separators.columns.push((
+                            column,
+                            false,
+                            Rectangle {
+                                y: start,
+                                height: (end - start).max(0.0),
+                                ..separator
+                            },
+                        ));
+
+                        start = end + metrics.rows[row] + self.padding_y * 2.0;
+                    }
+                }
+
+                separators.columns.push((
+                    column,
+                    false,
+                    Rectangle {
+                        y: start,
+                        height: (separator.height - start).max(0.0),
+                        ..separator
+                    },
+                ));
+            }
+        }
+
+        if self.separator_y > 0.0 {
+            let mut y = self.padding_y;
+            let separated = if self.more.is_some() {
+                metrics.rows.len()
+            } else {
+                metrics.rows.len().saturating_sub(1)
+            };
+
+            for (row, height) in metrics.rows[..separated].iter().enumerate() {
+                y += height + self.padding_y;
+
+                let separator = Rectangle {
+                    x: 0.0,
+                    y,
+                    width: size.width.max(metrics.content_width),
+                    height: self.separator_y,
+                };
+
+                y += self.separator_y + self.padding_y;
+
+                // The separator is interrupted by the cells spanning it
+                let mut start = separator.x;
+
+                for column in 0..columns {
+                    let cell = row * columns + column;
+
+                    if row > 0 && Self::merged(metrics, cell, cell + columns) {
+                        let x = self.column_x(metrics, column) - self.padding_x;
+
+                        separators.rows.push((
+                            row,
+                            Rectangle {
+                                x: start,
+                                width: (x - start).max(0.0),
+                                ..separator
+                            },
+                        ));
+
+                        start = x + metrics.columns[column] + self.padding_x * 2.0;
+                    }
+                }
+
+                separators.rows.push((
+                    row,
+                    Rectangle {
+                        x: start,
+                        width: (separator.x + separator.width - start).max(0.0),
+                        ..separator
+                    },
+                ));
+            }
+        }
+
+        separators
+    }
+}
+
+#[derive(Default)]
+struct Metrics {
+    columns: Vec<f32>,
+    rows: Vec<f32>,
+    gutter: f32,
+    resized: Vec<Option<f32>>,
+    rows_resized: Vec<Option<f32>>,
+    autofit: Reset,
+    autofitted: Vec<usize>,
+    reset: Reset,
+    drag: Option<Drag>,
+    reorder: Option<Reorder>,
+    cell_drag: Option<CellDrag>,
+    pressed: Option<usize>,
+    keys: Vec<Key>,
+    cells: usize,
+    content_width: f32,
+    scroll_x: f32,
+    scroller_grab: Option<f32>,
+    modifiers: keyboard::Modifiers,
+    separator_click: Option<(Boundary, advanced::mouse::Click)>,
+    header_click: Option<(usize, advanced::mouse::Click)>,
+    hovered_column: Option<usize>,
+    hovered_row: Option<usize>,
+    tier: f32,
+    fingerprints: HashMap<(Key, Key), u64>,
+    cell_keys: Vec<(Key, Key)>,
+    flashes: HashMap<usize, Instant>,
+    now: Option<Instant>,
+    widths: Transition,
+    heights: Transition,
+    spans: Vec<(usize, usize)>,
+    owners: Vec<usize>,
+    busy: Option<Instant>,
+    separators: Separators,
+}
+
+/// The separators of a laid out [`Table`], relative to its origin.
+#[derive(Default)]
+struct Separators {
+    /// The segments of the vertical separators after each column, and
+    /// whether they separate its header.
+    columns: Vec<(usize, bool, Rectangle)>,
+    /// The segments of the horizontal separators below each row, from top
+    /// to bottom.
+    rows: Vec<(usize, Rectangle)>,
+}
+
+/// The animated sizes of the columns or the rows of a [`Table`].
+#[derive(Default)]
+struct Transition {
+    targets: Vec<f32>,
+    shown: Vec<f32>,
+    from: Option<(Vec<f32>, Instant)>,
+}
+
+impl Transition {
+    /// Moves the shown sizes towards the ones just computed, starting a new
+    /// transition when they change. A snapped transition jumps to them.
+    fn animate(&mut self, sizes: &mut [f32], duration: Duration, now: Instant, snap: bool) {
+        let targets = sizes.to_vec();
+
+        if snap || self.shown.len() != targets.len() {
+            self.from = None;
+        } else if self.targets != targets {
+            self.from = Some((self.shown.clone(), now));
+        }
+
+        if let Some((from, start)) = &self.from {
+            let progress = now.saturating_duration_since(*start).as_secs_f32()
+                / duration.as_secs_f32().max(f32::EPSILON);
+
+            if progress < 1.0 {
+                // Ease out, so the sizes settle smoothly
+                let t = 1.0 - (1.0 - progress).powi(3);
+
+                for (size, from) in sizes.iter_mut().zip(from) {
+                    *size = from + (*size - from) * t;
+                }
+            } else {
+                self.from = None;
+            }
+        }
+
+        self.targets = targets;
+        self.shown = sizes.to_vec();
+    }
+
+    fn is_running(&self) -> bool {
+        self.from.is_some()
+    }
+}
+
+impl Metrics {
+    fn apply(&mut self, drag: Drag) {
+        match drag.boundary {
+            Boundary::Column(column) => self.resize(column, drag.current),
+            Boundary::Row(row) => self.resize_row(row, drag.current),
+        }
+    }
+
+    fn resize(&mut self, column: usize, width: f32) {
+        if self.resized.len() <= column {
+            self.resized.resize(column + 1, None);
+        }
+
+        self.resized[column] = Some(width);
+    }
+
+    fn resize_row(&mut self, row: usize, height: f32) {
+        if self.rows_resized.len() <= row {
+            self.rows_resized.resize(row + 1, None);
+        }
+
+        self.rows_resized[row] = Some(height);
+    }
+}
+
+/// The strategy used to apply the size of a column or row while resizing it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ResizeMode {
+    /// The [`Table`] is laid out continuously while dragging.
+    #[default]
+    Live,
+    /// A ghost line follows the cursor while dragging, and the size is
+    /// applied on release.
+    ///
+    /// Cheaper than [`ResizeMode::Live`] for huge tables.
+    Deferred,
+}
+
+/// The change of a row of a [`Table`] created with [`compare`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Change {
+    Added,
+    Removed,
+    Changed,
+}
+
+/// The spacing settings of a [`Table`] used by its layout.
+#[derive(Debug, Clone, Copy)]
+struct Config {
+    padding_x: f32,
+    padding_y: f32,
+    separator_x: f32,
+    separator_y: f32,
+    min_width: f32,
+    resize_step: Option<f32>,
+    shrink: bool,
+}
+
+impl Config {
+    /// Returns the size of the contents of a [`Table`] with the given [`Metrics`].
+    fn content(&self, metrics: &Metrics) -> Size {
+        let spacing_x = self.padding_x * 2.0 + self.separator_x;
+        let spacing_y = self.padding_y * 2.0 + self.separator_y;
+
+        Size::new(
+            // gutter + left pad + sum(fixed) + separators + right pad
+            metrics.gutter
+                + self.padding_x * 2.0
+                + metrics.columns.iter().sum::<f32>()
+                + spacing_x * metrics.columns.len().saturating_sub(1) as f32,
+            // top pad + rows + inter-row spacing + bottom pad
+            self.padding_y * 2.0
+                + metrics.rows.iter().sum::<f32>()
+                + spacing_y * metrics.rows.len().saturating_sub(1) as f32
+                - self.separator_y, // remove the last added separator_y
+        )
+    }
+
+    fn snap_up(&self, width: f32) -> f32 {
+        match self.resize_step {
+            Some(step) => (width / step).ceil() * step,
+            None => width,
+        }
+    }
+}
+
+/// The half-width of the area around a separator that can be dragged.
+const RESIZE_HANDLE: f32 = 4.0;
+
+/// The default text size, scaled by [`Table::scale`].
+const TEXT_SIZE: f32 = 16.0;
+
+/// The amount of rows sampled by [`Column::auto_precision`].
+const AUTO_PRECISION_SAMPLE: usize = 1000;
+
+/// The amount of dots of the spinner of a busy [`Table`].
+const SPINNER_DOTS: usize = 8;
+
+/// The radius of the spinner of a busy [`Table`].
+const SPINNER_RADIUS: f32 = 12.0;
+
+/// The diameter of the dots of the spinner of a busy [`Table`].
+const SPINNER_DOT: f32 = 5.0;
+
+/// The diameter of the insert and remove affordances of a header.
+const AFFORDANCE: f32 = 14.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Affordance {
+    Insert(usize),
+    Remove(usize),
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    boundary: Boundary,
+    origin: f32,
+    size: f32,
+    current: f32,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct CellDrag {
+    source: usize,
+    target: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Reorder {
+    row: usize,
+    target: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Boundary {
+    Column(usize),
+    Row(usize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum Reset {
+    #[default]
+    Idle,
+    Pending,
+    Done,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Table<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: R,
+{
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<Metrics>()
+    }
+
+    fn state(&self) -> tree::State {
+        let cell_keys = self.cell_keys();
+
+        tree::State::new(Metrics {
+            keys: self.columns.iter().map(Column_::key).collect(),
+            cells: self.cells.len(),
+            fingerprints: self.fingerprints(&cell_keys),
+            cell_keys,
+            ..Metrics::default()
+        })
+    }
+
+    fn children(&self) -> Vec<tree::Tree> {
+        self.cells
+            .iter()
+            .chain(&self.gutter)
+            .chain(self.groups.iter().map(|(_, header)| header))
+            .chain(&self.more)
+            .map(|cell| tree::Tree::new(cell.as_widget()))
+            .collect()
+    }
+
+    fn diff(&self, state: &mut tree::Tree) {
+        let keys: Vec<_> = self.columns.iter().map(Column_::key).collect();
+        let metrics = state.state.downcast_mut::<Metrics>();
+
+        // Cells follow the identity of their column, instead of its position
+        if metrics.keys != keys {
+            let old_keys = std::mem::take(&mut metrics.keys);
+            let old_columns = old_keys.len().max(1);
+            let old_cells = metrics.cells.min(state.children.len());
+
+            let sources: Vec<_> = keys
+                .iter()
+                .map(|key| old_keys.iter().position(|old| old == key))
+                .collect();
+
+            metrics.resized = sources
+                .iter()
+                .map(|source| source.and_then(|old| metrics.resized.get(old).copied().flatten()))
+                .collect();
+
+            let mut old: Vec<_> = state.children.drain(..).map(Some).collect();
+            let tail = old.split_off(old_cells);
+
+            state.children = (0..self.cells.len())
+                .map(|i| {
+                    let row = i / keys.len();
+
+                    sources[i % keys.len()]
+                        .map(|column| row * old_columns + column)
+                        .filter(|&old_cell| old_cell < old_cells)
+                        .and_then(|old_cell| old[old_cell].take())
+                        .unwrap_or_else(tree::Tree::empty)
+                })
+                .chain(tail.into_iter().flatten())
+                .collect();
+        }
+
+        let metrics = state.state.downcast_mut::<Metrics>();
+
+        metrics.keys = keys;
+        metrics.cells = self.cells.len();
+
+        let cell_keys = self.cell_keys();
+        let fingerprints = self.fingerprints(&cell_keys);
+
+        if self.flash.is_some() && !self.accessibility.reduced_motion {
+            let now = Instant::now();
+
+            // Ongoing flashes follow their cells
+            let flashing: HashMap<_, _> = metrics
+                .flashes
+                .drain()
+                .filter_map(|(cell, start)| Some((*metrics.cell_keys.get(cell)?, start)))
+                .collect();
+
+            for (cell, key) in cell_keys.iter().enumerate() {
+                let changed = fingerprints
+                    .get(key)
+                    .zip(metrics.fingerprints.get(key))
+                    .is_some_and(|(new, old)| new != old);
+
+                if changed {
+                    metrics.flashes.insert(cell, now);
+                } else if let Some(&start) = flashing.get(key) {
+                    metrics.flashes.insert(cell, start);
+                }
+            }
+        }
+
+        metrics.fingerprints = fingerprints;
+        metrics.cell_keys = cell_keys;
+
+        let children: Vec<_> = self
+            .cells
+            .iter()
+            .chain(&self.gutter)
+            .chain(self.groups.iter().map(|(_, header)| header))
+            .chain(&self.more)
+            .collect();
+
+        state.diff_children(&children);
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut tree::Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let metrics = tree.state.downcast_mut::<Metrics>();
+        let columns = self.columns.len();
+        let limits = limits.width(self.width).height(self.height);
+
+        // Every column may be hidden
+        if columns == 0 {
+            metrics.columns.clear();
+            metrics.rows.clear();
+            metrics.spans.clear();
+            metrics.owners.clear();
+            metrics.separators = Separators::default();
+            metrics.content_width = 0.0;
+
+            return layout::Node::new(limits.resolve(self.width, self.height, Size::ZERO));
+        }
+
+        let rows = self.cells.len() / columns;
+        let max_limits = limits.width(self.max_width).height(self.height).max();
+
+        // Columns stop shrinking at the minimum width; the rest is scrolled
+        let available = limits.max();
+        let available = Size::new(available.width.max(self.min_width), available.height);
+
+        let mut cells = Vec::with_capacity(self.cells.len() + self.gutter.len());
+        cells.resize(self.cells.len(), layout::Node::default());
+
+        // ---------- GUTTER ----------
+        // Row numbers are measured first, since they take width from the columns.
+        let mut gutter: Vec<_> = self
+            .gutter
+            .iter_mut()
+            .zip(&mut tree.children[self.cells.len()..])
+            .map(|(number, state)| {
+                number.as_widget_mut().layout(
+                    state,
+                    renderer,
+                    &layout::Limits::new(Size::ZERO, available),
+                )
+            })
+            .collect();
+
+        let gutter_width = gutter
+            .iter()
+            .map(|number| number.size().width)
+            .fold(0.0, f32::max);
+
+        metrics.gutter = if gutter.is_empty() {
+            0.0
+        } else {
+            gutter_width + self.padding_x * 2.0 + self.separator_x
+        };
+
+        let left = metrics.gutter + self.padding_x;
+
+        let config = self.config();
+        let (spans, owners) = self.owners();
+        let sizes: Vec<_> = self
+            .cells
+            .iter()
+            .map(|cell| cell.as_widget().size())
+            .collect();
+
+        let sizings: Vec<_> = self
+            .columns
+            .iter()
+            .map(|column| Sizing {
+                fixed: column.fixed,
+                percent: column.percent,
+                weight: column
+                    .weight
+                    .unwrap_or_else(|| f32::from(column.width.fill_factor().max(1))),
+                priority: column.shrink_priority,
+            })
+            .collect();
+
+        fluid(
+            config,
+            metrics,
+            &sizings,
+            available,
+            max_limits.width,
+            &sizes,
+            |i, limits| {
+                if owners[i] != i {
+                    return Size::ZERO;
+                }
+
+                cells[i] =
+                    self.cells[i]
+                        .as_widget_mut()
+                        .layout(&mut tree.children[i], renderer, limits);
+
+                // Spanning cells are measured across their columns afterwards
+                let (span_x, span_y) = spans[i];
+                let size = cells[i].size();
+
+                Size::new(
+                    if span_x > 1 { 0.0 } else { size.width },
+                    if span_y > 1 { 0.0 } else { size.height },
+                )
+            },
+        );
+
+        if let Some(duration) = self.transition
+            && !self.accessibility.reduced_motion
+        {
+            let snap = metrics.drag.is_some();
+
+            metrics
+                .widths
+                .animate(&mut metrics.columns, duration, Instant::now(), snap);
+        }
+
+        let spacing_x = self.padding_x * 2.0 + self.separator_x;
+        let spacing_y = self.padding_y * 2.0 + self.separator_y;
+
+        metrics.spans = spans;
+        metrics.owners = owners;
+
+        // ---------- SPANS ----------
+        // Spanning cells take the width of their columns, growing their last row
+        for i in 0..self.cells.len() {
+            if metrics.spans[i] == (1, 1) || metrics.owners[i] != i {
+                continue;
+            }
+
+            let last = i / columns + metrics.spans[i].1 - 1;
+            let area = self.span_size(metrics, i);
+
+            cells[i] = self.cells[i].as_widget_mut().layout(
+                &mut tree.children[i],
+                renderer,
+                &layout::Limits::new(Size::ZERO, Size::new(area.width, f32::INFINITY))
+                    .width(Length::Fixed(area.width)),
+            );
+
+            if metrics.rows_resized.get(last).copied().flatten().is_none() {
+                metrics.rows[last] += (cells[i].size().height - area.height).max(0.0);
+            }
+        }
+
+        if let Some(duration) = self.row_transition
+            && !self.accessibility.reduced_motion
+        {
+            let snap = metrics.drag.is_some();
+
+            metrics
+                .heights
+                .animate(&mut metrics.rows, duration, Instant::now(), snap);
+        }
+
+        // ---------- GROUPS ----------
+        // Group headers take a tier at the top of the header row
+        let first = self.cells.len() + self.gutter.len();
+
+        let mut groups: Vec<_> = self
+            .groups
+            .iter_mut()
+            .zip(&mut tree.children[first..])
+            .map(|((range, header), state)| {
+                let width = metrics.columns[range.clone()].iter().sum::<f32>()
+                    + spacing_x * range.len().saturating_sub(1) as f32;
+
+                let node = header.as_widget_mut().layout(
+                    state,
+                    renderer,
+                    &layout::Limits::new(Size::ZERO, Size::new(width, f32::INFINITY)),
+                );
+
+                (node, range.start, width)
+            })
+            .collect();
+
+        metrics.tier = groups
+            .iter()
+            .map(|(node, _, _)| node.size().height + spacing_y)
+            .fold(0.0, f32::max);
+
+        if let Some(header) = metrics.rows.first_mut() {
+            *header += metrics.tier;
+        }
+
+        for (node, start, width) in &mut groups {
+            let height = metrics.tier - spacing_y;
+
+            node.move_to_mut((self.column_x(metrics, *start), self.padding_y));
+            node.align_mut(
+                Alignment::Center,
+                Alignment::Center,
+                Size::new(*width, height),
+            );
+        }
+
+        // ---------- THIRD PASS (position) ----------
+        let mut x = left;
+        let mut y = self.padding_y;
+
+        for (i, cell) in cells.iter_mut().enumerate() {
+            let row = i / columns;
+            let column = i % columns;
+
+            if column == 0 {
+                x = left;
+
+                if row > 0 {
+                    y += metrics.rows[row - 1] + spacing_y;
+                }
+            }
+
+            let Column_ {
+                align_x,
+                align_y,
+                header_align_x,
+                header_align_y,
+                ..
+            } = &self.columns[column];
+
+            // Headers and cells may override the alignment of their column
+            let (cell_x, cell_y) = i
+                .checked_sub(columns)
+                .map_or((*header_align_x, *header_align_y), |i| self.alignments[i]);
+
+            // Headers sit below the tier of the groups
+            let tier = if row == 0 { metrics.tier } else { 0.0 };
+            let area = self.span_size(metrics, i);
+
+            cell.move_to_mut((x, y + tier));
+            cell.align_mut(
+                Alignment::from(cell_x.unwrap_or(*align_x)),
+                Alignment::from(cell_y.unwrap_or(*align_y)),
+                Size::new(area.width, area.height - tier),
+            );
+
+            x += metrics.columns[column] + spacing_x;
+        }
+
+        // Row numbers are aligned to the right of the gutter
+        let mut y = self.padding_y;
+
+        for (row, number) in gutter.iter_mut().enumerate() {
+            if row > 0 {
+                y += metrics.rows[row - 1] + spacing_y;
+            }
+
+            number.move_to_mut((self.padding_x, y));
+            number.align_mut(
+                Alignment::End,
+                Alignment::Center,
+                Size::new(gutter_width, metrics.rows[row]),
+            );
+        }
+
+        cells.extend(gutter);
+        cells.extend(groups.into_iter().map(|(node, _, _)| node));
+
+        // Intrinsic table size
+        let mut content = config.content(metrics);
+
+        // The "more" row spans all the columns below the last row
+        if let Some(more) = &mut self.more {
+            let y = self.padding_y + metrics.rows.iter().sum::<f32>() + spacing_y * rows as f32;
+            let state =
+                &mut tree.children[self.cells.len() + self.gutter.len() + self.groups.len()];
+
+            let node = more
+                .as_widget_mut()
+                .layout(
+                    state,
+                    renderer,
+                    &layout::Limits::new(
+                        Size::ZERO,
+                        Size::new(content.width - left - self.padding_x, f32::INFINITY),
+                    ),
+                )
+                .move_to((left, y));
+
+            content.height = y + node.size().height + self.padding_y;
+            cells.push(node);
+        }
+
+        let mut intrinsic = limits.resolve(self.width, self.height, content);
+
+        // A reserved scrollbar takes its own space below the last row
+        if self.scrollbar.placement == Placement::Reserved
+            && self.min_width > 0.0
+            && content.width > intrinsic.width
+        {
+            let reserved = Size::new(
+                content.width,
+                content.height + self.scrollbar.width + self.scrollbar.margin * 2.0,
+            );
+
+            intrinsic = limits.resolve(self.width, self.height, reserved);
+        }
+
+        metrics.content_width = content.width;
+        metrics.scroll_x = metrics
+            .scroll_x
+            .clamp(0.0, (content.width - intrinsic.width).max(0.0));
+        metrics.separators = self.separators(metrics, intrinsic);
+
+        layout::Node::with_children(intrinsic, cells)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut tree::Tree,
+        event: &iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn advanced::Clipboard,
+        shell: &mut advanced::Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let metrics = tree.state.downcast_mut::<Metrics>();
+
+        if metrics.autofit == Reset::Pending || metrics.reset == Reset::Pending {
+            shell.invalidate_layout();
+            shell.request_redraw();
+        } else if metrics.reset == Reset::Done {
+            metrics.reset = Reset::Idle;
+
+            if let Some(on_reset) = &self.on_widths_reset {
+                shell.publish(on_reset(metrics.columns.clone()));
+            }
+        }
+
+        // Autofitted columns are kept like resized ones
+        if metrics.autofit == Reset::Done {
+            metrics.autofit = Reset::Idle;
+
+            if let Some(on_resize) = &self.on_column_resize {
+                for column in std::mem::take(&mut metrics.autofitted) {
+                    if let (Some(source), Some(width)) =
+                        (self.columns.get(column), metrics.columns.get(column))
+                    {
+                        shell.publish(on_resize(source.source, *width));
+                    }
+                }
+            }
+        }
+
+        let bounds = layout.bounds();
+
+        if let iced::Event::Window(window::Event::RedrawRequested(now)) = event {
+            metrics.now = Some(*now);
+
+            if metrics.widths.is_running() || metrics.heights.is_running() {
+                shell.invalidate_layout();
+                shell.request_redraw();
+            }
+
+            if let Some((duration, _)) = self.flash {
+                metrics.flashes.retain(|_, start| *now < *start + duration);
+
+                if !metrics.flashes.is_empty() {
+                    shell.request_redraw();
+                }
+            }
+        }
+
+        // A busy table spins until it is done, ignoring any input
+        if !self.busy {
+            metrics.busy = None;
+        } else {
+            metrics.busy.get_or_insert_with(Instant::now);
+
+            if let iced::Event::Window(window::Event::RedrawRequested(_)) = event
+                && !self.accessibility.reduced_motion
+            {
+                shell.request_redraw();
+            }
+
+            if matches!(event, iced::Event::Mouse(_) | iced::Event::Touch(_))
+                && cursor.is_over(bounds)
+            {
+                shell.capture_event();
+            }
+
+            return;
+        }
+
+        if let iced::Event::Mouse(mouse::Event::CursorMoved { .. } | mouse::Event::CursorLeft) =
+            event
+        {
+            let hovered = self
+                .position(metrics, bounds, cursor)
+                .and_then(|position| self.column_at(metrics, position.x));
+
+            if std::mem::replace(&mut metrics.hovered_column, hovered) != hovered {
+                shell.request_redraw();
+            }
+
+            // The header is not highlighted
+            let hovered = self
+                .position(metrics, bounds, cursor)
+                .and_then(|position| self.row_at(metrics, position.y))
+                .filter(|row| *row > 0);
+
+            if std::mem::replace(&mut metrics.hovered_row, hovered) != hovered {
+                shell.request_redraw();
+            }
+        }
+
+        if let iced::Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) = event {
+            metrics.modifiers = *modifiers;
+        }
+
+        if let Some((track, scroller)) = self.scroller(metrics, bounds, viewport) {
+            let max_scroll = metrics.content_width - bounds.width;
+
+            match event {
+                iced::Event::Mouse(mouse::Event::WheelScrolled { delta })
+                    if cursor.is_over(bounds) =>
+                {
+                    let (x, y) = match *delta {
+                        mouse::ScrollDelta::Lines { x, y } => (x * 60.0, y * 60.0),
+                        mouse::ScrollDelta::Pixels { x, y } => (x, y),
+                    };
+
+                    let delta = if x == 0.0 && metrics.modifiers.shift() {
+                        y
+                    } else {
+                        x
+                    };
+
+                    if self.scroll_to(metrics, bounds, metrics.scroll_x - delta) {
+                        shell.request_redraw();
+                        shell.capture_event();
+                        return;
+                    }
+                }
+                iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                    if let Some(position) = cursor.position_over(track) {
+                        let grab = if scroller.contains(position) {
+                            position.x - scroller.x
+                        } else {
+                            scroller.width / 2.0
+                        };
+
+                        metrics.scroller_grab = Some(grab);
+
+                        let _ = self.scroll_to(
+                            metrics,
+                            bounds,
+                            (position.x - grab - track.x) / (track.width - scroller.width)
+                                * max_scroll,
+                        );
+
+                        shell.request_redraw();
+                        shell.capture_event();
+                        return;
+                    }
+                }
+                iced::Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                    if let Some(grab) = metrics.scroller_grab {
+                        let _ = self.scroll_to(
+                            metrics,
+                            bounds,
+                            (position.x - grab - track.x) / (track.width - scroller.width)
+                                * max_scroll,
+                        );
+
+                        shell.request_redraw();
+                        shell.capture_event();
+                        return;
+                    }
+                }
+                iced::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                    if metrics.scroller_grab.take().is_some() =>
+                {
+                    shell.capture_event();
+                    return;
+                }
+                _ => {}
+            }
+        }
+
+        if self.on_column_insert.is_some() || self.on_column_remove.is_some() {
+            match event {
+                iced::Event::Mouse(mouse::Event::CursorMoved { .. } | mouse::Event::CursorLeft) => {
+                    shell.request_redraw();
+                }
+                iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                    let message = self
+                        .position(metrics, bounds, cursor)
+                        .and_then(|position| self.affordance_at(metrics, position))
+                        .and_then(|affordance| match affordance {
+                            Affordance::Insert(position) => {
+                                self.on_column_insert.as_ref().map(|f| f(position))
+                            }
+                            Affordance::Remove(column) => {
+                                self.on_column_remove.as_ref().map(|f| f(column))
+                            }
+                        });
+
+                    if let Some(message) = message {
+                        shell.publish(message);
+                        shell.capture_event();
+                        return;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if (self.on_separator_click.is_some() || self.on_separator_double_click.is_some())
+            && let iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) = event
+            && let Some(position) = self.position(metrics, bounds, cursor)
+            && let Some(boundary) = self.separator_at(metrics, position)
+        {
+            let previous = metrics
+                .separator_click
+                .filter(|(previous, _)| *previous == boundary)
+                .map(|(_, click)| click);
+
+            let click = advanced::mouse::Click::new(position, mouse::Button::Left, previous);
+            metrics.separator_click = Some((boundary, click));
+
+            let separator = match boundary {
+                Boundary::Column(column) => Separator::Column(self.columns[column].source),
+                Boundary::Row(row) => Separator::Row(row - 1),
+            };
+
+            let handler = match click.kind() {
+                advanced::mouse::click::Kind::Single => &self.on_separator_click,
+                _ => &self.on_separator_double_click,
+            };
+
+            if let Some(handler) = handler {
+                shell.publish(handler(separator));
+            }
+
+            // Resizable separators are also dragged
+            if self.boundary_at(metrics, position).is_none() {
+                shell.capture_event();
+                return;
+            }
+        }
+
+        if self.resize.is_some() || self.on_row_resize.is_some() {
+            let mode = self.resize.unwrap_or_default();
+
+            match event {
+                iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                    if let Some(position) = self.position(metrics, bounds, cursor)
+                        && let Some(boundary) = self.boundary_at(metrics, position)
+                        && let Some(pressed) = cursor.position()
+                    {
+                        // The origin is kept in window coordinates, like the moves
+                        let (origin, size) = match boundary {
+                            Boundary::Column(column) => (pressed.x, metrics.columns[column]),
+                            Boundary::Row(row) => (pressed.y, metrics.rows[row]),
+                        };
+
+                        metrics.drag = Some(Drag {
+                            boundary,
+                            origin,
+                            size,
+                            current: size,
+                        });
+
+                        shell.capture_event();
+                        return;
+                    }
+                }
+                iced::Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                    if let Some(drag) = &mut metrics.drag {
+                        drag.current = match drag.boundary {
+                            Boundary::Column(_) => {
+                                self.snap((drag.size + position.x - drag.origin).max(0.0))
+                            }
+                            Boundary::Row(_) => (drag.size + position.y - drag.origin)
+                                .clamp(self.min_row_height, self.max_row_height),
+                        };
+
+                        if mode == ResizeMode::Live {
+                            let drag = *drag;
+
+                            metrics.apply(drag);
+                            shell.invalidate_layout();
+                        }
+
+                        shell.request_redraw();
+                        shell.capture_event();
+                        return;
+                    }
+                }
+                iced::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                    if let Some(drag) = metrics.drag.take() {
+                        metrics.apply(drag);
+
+                        match drag.boundary {
+                            Boundary::Column(column) => {
+                                if let Some(on_column_resize) = &self.on_column_resize {
+                                    shell.publish(on_column_resize(
+                                        self.columns[column].source,
+                                        drag.current,
+                                    ));
+                                }
+                            }
+                            Boundary::Row(row) => {
+                                if let Some(on_row_resize) = &self.on_row_resize {
+                                    shell.publish(on_row_resize(row - 1, drag.current));
+                                }
+                            }
+                        }
+
+                        shell.invalidate_layout();
+                        shell.request_redraw();
+                        shell.capture_event();
+                        return;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(on_double_click) = &self.on_header_double_click
+            && let iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) = event
+            && let Some(position) = self.position(metrics, bounds, cursor)
+            && self.row_at(metrics, position.y) == Some(0)
+            && let Some(column) = self.column_at(metrics, position.x)
+        {
+            let previous = metrics
+                .header_click
+                .filter(|(previous, _)| *previous == column)
+                .map(|(_, click)| click);
+
+            let click = advanced::mouse::Click::new(position, mouse::Button::Left, previous);
+            metrics.header_click = Some((column, click));
+
+            if click.kind() == advanced::mouse::click::Kind::Double {
+                shell.publish(on_double_click(self.columns[column].source));
+                shell.capture_event();
+                return;
+            }
+        }
+
+        if let Some(on_reorder) = &self.on_row_reorder {
+            match event {
+                iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                    if let Some(row) = self.handle_at(metrics, bounds, cursor) {
+                        metrics.reorder = Some(Reorder { row, target: row });
+
+                        shell.capture_event();
+                        return;
+                    }
+                }
+                iced::Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                    if let Some(reorder) = metrics.reorder {
+                        metrics.reorder = Some(Reorder {
+                            target: self.drop_target(metrics, position.y - bounds.y),
+                            ..reorder
+                        });
+
+                        shell.request_redraw();
+                        shell.capture_event();
+                        return;
+                    }
+                }
+                iced::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                    if let Some(Reorder { row, target }) = metrics.reorder.take() {
+                        // The target is an insertion point among the current rows
+                        let target = if target > row { target - 1 } else { target };
+
+                        if target != row {
+                            shell.publish(on_reorder(row, target));
+                        }
+
+                        shell.request_redraw();
+                        shell.capture_event();
+                        return;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(on_drop) = &self.on_cell_drop {
+            match event {
+                iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                    if let Some(source) = self.draggable_at(metrics, bounds, cursor) {
+                        metrics.cell_drag = Some(CellDrag {
+                            source,
+                            target: None,
+                        });
+
+                        shell.capture_event();
+                        return;
+                    }
+                }
+                iced::Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                    if let Some(drag) = metrics.cell_drag {
+                        metrics.cell_drag = Some(CellDrag {
+                            target: self.draggable_at(metrics, bounds, cursor),
+                            ..drag
+                        });
+
+                        shell.request_redraw();
+                        shell.capture_event();
+                        return;
+                    }
+                }
+                iced::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                    if let Some(CellDrag { source, target }) = metrics.cell_drag.take() {
+                        if let Some(target) = target.filter(|target| *target != source) {
+                            let columns = self.columns.len();
+                            let location = |cell: usize| RowColumn {
+                                row: cell / columns,
+                                column: self.columns[cell % columns].source,
+                            };
+
+                            shell.publish(on_drop(location(source), location(target)));
+                        }
+
+                        shell.request_redraw();
+                        shell.capture_event();
+                        return;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        match event {
+            iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if let Some(cell) = self.press_at(metrics, bounds, cursor) {
+                    metrics.pressed = Some(cell);
+
+                    shell.request_redraw();
+                    shell.capture_event();
+                    return;
+                }
+            }
+            iced::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                if let Some(cell) = metrics.pressed.take() {
+                    if self.press_at(metrics, bounds, cursor) == Some(cell)
+                        && let Some(Some(on_press)) = self.presses.get(cell)
+                    {
+                        shell.publish(on_press());
+                    }
+
+                    shell.request_redraw();
+                    shell.capture_event();
+                    return;
+                }
+            }
+            _ => {}
+        }
+
+        if let Some(on_press) = &self.on_row_number_press
+            && let iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) = event
+            && let Some(row) = self.row_number_at(tree, bounds, cursor, viewport)
+        {
+            shell.publish(on_press(row));
+            shell.capture_event();
+            return;
+        }
+
+        let metrics = tree.state.downcast_ref::<Metrics>();
+
+        if let Some(on_show_more) = &self.on_show_more
+            && let iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) = event
+            && self.more_at(metrics, bounds, cursor)
+        {
+            shell.publish(on_show_more());
+            shell.capture_event();
+            return;
+        }
+
+        let is_hovered = cursor.is_over(bounds);
+        let cursor = self.content_cursor(metrics, bounds, cursor);
+        let viewport = &(*viewport + Vector::new(metrics.scroll_x, 0.0));
+
+        // Cells shown on hover appear and disappear with the cursor
+        if let iced::Event::Mouse(mouse::Event::CursorMoved { .. } | mouse::Event::CursorLeft) =
+            event
+            && self.columns.iter().any(|column| column.hover)
+        {
+            shell.request_redraw();
+        }
+
+        let concealed: Vec<_> = (0..self.cells.len())
+            .map(|i| self.conceals(metrics, bounds, cursor, i))
+            .collect();
+
+        for (((cell, state), layout), concealed) in self
+            .cells
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .zip(concealed)
+        {
+            if concealed {
+                continue;
+            }
+
+            cell.as_widget_mut().update(
+                state, event, layout, cursor, renderer, clipboard, shell, viewport,
+            );
+        }
+
+        // Cells copy their own contents first, like a focused text input
+        if !self.export.is_empty()
+            && !shell.is_event_captured()
+            && is_hovered
+            && let iced::Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                physical_key,
+                modifiers,
+                ..
+            }) = event
+            && modifiers.command()
+            && key.to_latin(*physical_key) == Some('c')
+        {
+            clipboard.write(advanced::clipboard::Kind::Standard, self.tsv());
+            shell.capture_event();
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &tree::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let metrics = tree.state.downcast_ref::<Metrics>();
+        let bounds = layout.bounds();
+
+        let Some((track, scroller)) = self.scroller(metrics, bounds, viewport) else {
+            self.draw_content(tree, renderer, theme, style, layout, cursor, viewport);
+            self.draw_busy(metrics, renderer, theme, style, bounds, viewport);
+            return;
+        };
+
+        let translation = Vector::new(metrics.scroll_x, 0.0);
+
+        renderer.with_layer(bounds, |renderer| {
+            renderer.with_translation(-translation, |renderer| {
+                self.draw_content(
+                    tree,
+                    renderer,
+                    theme,
+                    style,
+                    layout,
+                    self.content_cursor(metrics, bounds, cursor),
+                    &(*viewport + translation),
+                );
+            });
+        });
+
+        let appearance = self.appearance(theme, style);
+
+        renderer.with_layer(track, |renderer| {
+            if let Some(rail) = appearance.rail {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: track,
+                        border: appearance.scroller_border,
+                        ..renderer::Quad::default()
+                    },
+                    rail,
+                );
+            }
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: scroller,
+                    border: appearance.scroller_border,
+                    ..renderer::Quad::default()
+                },
+                appearance.scroller,
+            );
+        });
+
+        self.draw_busy(metrics, renderer, theme, style, bounds, viewport);
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &tree::Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let metrics = tree.state.downcast_ref::<Metrics>();
+        let bounds = layout.bounds();
+
+        if self.busy && cursor.is_over(bounds) {
+            return mouse::Interaction::Wait;
+        }
+
+        if metrics.scroller_grab.is_some()
+            || self
+                .scroller(metrics, bounds, viewport)
+                .is_some_and(|(track, _)| cursor.is_over(track))
+        {
+            return mouse::Interaction::Idle;
+        }
+
+        if metrics.drag.is_none()
+            && self
+                .position(metrics, bounds, cursor)
+                .and_then(|position| self.affordance_at(metrics, position))
+                .is_some()
+        {
+            return mouse::Interaction::Pointer;
+        }
+
+        let boundary = metrics.drag.map(|drag| drag.boundary).or_else(|| {
+            self.position(metrics, bounds, cursor)
+                .and_then(|position| self.boundary_at(metrics, position))
+        });
+
+        if metrics.reorder.is_some() || metrics.cell_drag.is_some() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        if self.on_cell_drop.is_some() && self.draggable_at(metrics, bounds, cursor).is_some() {
+            return mouse::Interaction::Grab;
+        }
+
+        if self.on_row_reorder.is_some() && self.handle_at(metrics, bounds, cursor).is_some() {
+            return mouse::Interaction::Grab;
+        }
+
+        match boundary {
+            Some(Boundary::Column(_)) => return mouse::Interaction::ResizingHorizontally,
+            Some(Boundary::Row(_)) => return mouse::Interaction::ResizingVertically,
+            None => {}
+        }
+
+        if (self.on_separator_click.is_some() || self.on_separator_double_click.is_some())
+            && self
+                .position(metrics, bounds, cursor)
+                .and_then(|position| self.separator_at(metrics, position))
+                .is_some()
+        {
+            return mouse::Interaction::Pointer;
+        }
+
+        if self.press_at(metrics, bounds, cursor).is_some()
+            || self.on_row_number_press.is_some()
+                && self.row_number_at(tree, bounds, cursor, viewport).is_some()
+            || self.on_show_more.is_some() && self.more_at(metrics, bounds, cursor)
+        {
+            return mouse::Interaction::Pointer;
+        }
+
+        let cursor = self.content_cursor(metrics, bounds, cursor);
+        let viewport = &(*viewport + Vector::new(metrics.scroll_x, 0.0));
+
+        self.cells
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .enumerate()
+            .filter(|(i, _)| !self.conceals(metrics, bounds, cursor, *i))
+            .map(|(_, ((cell, state), layout))| {
+                cell.as_widget()
+                    .mouse_interaction(state, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut tree::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.custom(
+            self.id.as_ref(),
+            layout.bounds(),
+            tree.state.downcast_mut::<Metrics>(),
+        );
+
+        operation.container(self.id.as_ref(), layout.bounds());
+
+        // Every body cell is a container, so operations can be scoped to it
+        let columns = self.columns.len();
+
+        operation.traverse(&mut |operation| {
+            for (i, ((cell, state), layout)) in self
+                .cells
+                .iter_mut()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .enumerate()
+            {
+                if let Some(body) = i.checked_sub(columns) {
+                    let row = body / columns;
+                    let column = &self.columns[body % columns];
+
+                    // Cells with a key are nested in a second container
+                    let mut operate_cell = |operation: &mut dyn Operation| match column.id {
+                        Some(key) => {
+                            operation.container(
+                                Some(&cell_id(row, CellColumn::Key(key))),
+                                layout.bounds(),
+                            );
+                            operation.traverse(&mut |operation| {
+                                cell.as_widget_mut()
+                                    .operate(state, layout, renderer, operation);
+                            });
+                        }
+                        None => cell
+                            .as_widget_mut()
+                            .operate(state, layout, renderer, operation),
+                    };
+
+                    operation.container(Some(&cell_id(row, column.source)), layout.bounds());
+                    operation.traverse(&mut |operation| operate_cell(operation));
+                } else {
+                    cell.as_widget_mut()
+                        .operate(state, layout, renderer, operation);
+                }
+            }
+
+            // Row numbers, group headers and the "more" row follow the cells
+            let others = self
+                .gutter
+                .iter_mut()
+                .chain(self.groups.iter_mut().map(|(_, header)| header))
+                .chain(&mut self.more);
+
+            for ((other, state), layout) in others
+                .zip(tree.children.iter_mut().skip(self.cells.len()))
+                .zip(layout.children().skip(self.cells.len()))
+            {
+                other
+                    .as_widget_mut()
+                    .operate(state, layout, renderer, operation);
+            }
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        state: &'b mut tree::Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: iced::Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let metrics = state.state.downcast_ref::<Metrics>();
+        let scroll_x = metrics.scroll_x;
+        let bounds = layout.bounds();
+
+        // The ghost of a deferred resize is drawn above the cells
+        let ghost = self
+            .ghost(metrics, bounds.size())
+            .and_then(|(ghost, boundary)| {
+                let visible = bounds + translation;
+                let ghost = (ghost + Vector::new(visible.x - scroll_x, visible.y))
+                    .intersection(&visible)?;
+
+                Some((ghost, boundary))
+            });
+
+        let Self {
+            cells,
+            class,
+            accessibility,
+            ..
+        } = self;
+
+        let overlays: Vec<_> = overlay::from_children(
+            cells,
+            state,
+            layout,
+            renderer,
+            viewport,
+            translation - Vector::new(scroll_x, 0.0),
+        )
+        .into_iter()
+        .chain(ghost.map(|(bounds, boundary)| {
+            overlay::Element::new(Box::new(Ghost {
+                bounds,
+                boundary,
+                class,
+                accessibility: *accessibility,
+            }))
+        }))
+        .collect();
+
+        (!overlays.is_empty()).then(|| overlay::Group::with_children(overlays).overlay())
+    }
+}
+
+/// Produces the [`Style`] of a [`Table`], honoring its [`Accessibility`].
+fn appearance<Theme: Catalog>(
+    theme: &Theme,
+    class: &Theme::Class<'_>,
+    accessibility: Accessibility,
+    defaults: &renderer::Style,
+) -> Style {
+    let style = theme.style(class);
+
+    if !accessibility.high_contrast {
+        return style;
+    }
+
+    // Separators and indicators take the color of the text
+    let contrast = Background::from(defaults.text_color);
+
+    Style {
+        separator_x: contrast,
+        separator_y: contrast,
+        separator_header_x: contrast,
+        separator_header_y: contrast,
+        separator_hovered: style.separator_hovered.map(|_| contrast),
+        scroller: contrast,
+        spinner: defaults.text_color,
+        ..style
+    }
+}
+
+/// The ghost line of a deferred resize, drawn as an overlay so it stays
+/// above the contents of a [`Table`].
+struct Ghost<'a, 'b, Theme: Catalog> {
+    bounds: Rectangle,
+    boundary: Boundary,
+    class: &'b Theme::Class<'a>,
+    accessibility: Accessibility,
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer> for Ghost<'_, '_, Theme>
+where
+    Theme: Catalog,
+    Renderer: R,
+{
+    fn layout(&mut self, _renderer: &Renderer, _bounds: Size) -> layout::Node {
+        layout::Node::new(self.bounds.size()).move_to(self.bounds.position())
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+    ) {
+        let style = appearance(theme, self.class, self.accessibility, style);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: layout.bounds(),
+                snap: true,
+                ..renderer::Quad::default()
+            },
+            match self.boundary {
+                Boundary::Column(_) => style.separator_x,
+                Boundary::Row(_) => style.separator_y,
+            },
+        );
+    }
+}
+
+/// How a column of a [`Table`] takes its share of the available width.
+#[derive(Debug, Clone, Copy)]
+struct Sizing {
+    /// The fixed width of the column, if any.
+    fixed: Option<f32>,
+    /// The percentage of the content width taken by the column, if any.
+    percent: Option<f32>,
+    /// The amount of shares of the remaining width taken by the column.
+    weight: f32,
+    /// The order in which the column gives up width when shrinking to fit.
+    priority: u8,
+}
+
+impl Default for Sizing {
+    fn default() -> Self {
+        Self {
+            fixed: None,
+            percent: None,
+            weight: 1.0,
+            priority: 0,
+        }
+    }
+}
+
+/// Measures the cells of a [`Table`] and shares the available width among
+/// its columns, storing the resulting widths and heights in the [`Metrics`].
+///
+/// Every column may take a percentage of the content width, or shares of the
+/// remaining width according to its weight. The `layout`
+/// function lays out the cell with the given index and returns its size.
+fn fluid(
+    config: Config,
+    metrics: &mut Metrics,
+    sizings: &[Sizing],
+    available: Size,
+    max_width: f32,
+    sizes: &[Size<Length>],
+    mut layout: impl FnMut(usize, &layout::Limits) -> Size,
+) {
+    let columns = sizings.len();
+    let rows = sizes.len() / columns.max(1);
+    let left = metrics.gutter + config.padding_x;
+
+    // Only the columns changed by an autofit are reported
+    let previous = std::mem::replace(&mut metrics.columns, vec![0.0; columns]);
+    metrics.rows = vec![0.0; rows];
+
+    // We keep row height logic (factors & distribution) intact
+    let mut total_row_factors = 0;
+    let mut total_fluid_height = 0.0;
+    let mut row_factor = 0;
+    let mut fluid_rows = vec![false; rows];
+    let mut headers = vec![0.0; columns];
+
+    // spacing_x includes per-column left+right padding plus the separator
+    let spacing_x = config.padding_x * 2.0 + config.separator_x;
+    let spacing_y = config.padding_y * 2.0 + config.separator_y;
+
+    // ---------- FIRST PASS ----------
+    // Ignore declared column widths: treat as Shrink to measure intrinsic widths per column.
+    let mut x = left;
+    let mut y = config.padding_y;
+
+    for (i, size_req) in sizes.iter().enumerate() {
+        let row = i / columns;
+        let column = i % columns;
+
+        if column == 0 {
+            x = left;
+
+            if row > 0 {
+                y += metrics.rows[row - 1] + spacing_y;
+
+                if row_factor != 0 {
+                    total_fluid_height += metrics.rows[row - 1];
+                    total_row_factors += row_factor;
+                    row_factor = 0;
+                }
+            }
+        }
+
+        let height_factor = size_req.height.fill_factor();
+        row_factor = row_factor.max(height_factor);
+
+        // Layout with width forced to Shrink, so we can measure intrinsic content width.
+        let max = Size::new(available.width - x, available.height - y);
+        let pass1_limits = layout::Limits::new(Size::ZERO, max).width(Length::Shrink);
+
+        let size = layout(i, &pass1_limits);
+        let sz = pass1_limits.resolve(Length::Shrink, Length::Shrink, size);
+
+        // Per-column intrinsic width (content), accumulated as max
+        metrics.columns[column] = metrics.columns[column].max(sz.width);
+
+        if row == 0 {
+            headers[column] = sz.width;
+        }
+
+        // Row height metrics only for non-fluid rows (existing behavior preserved)
+        if height_factor == 0 && !size_req.height.is_fill() {
+            metrics.rows[row] = metrics.rows[row].max(sz.height);
+        } else {
+            fluid_rows[row] = true;
+        }
+
+        x += sz.width + spacing_x;
+    }
+
+    // Account for last row's factors
+    if row_factor != 0 && rows > 0 {
+        total_fluid_height += metrics.rows[rows - 1];
+        total_row_factors += row_factor;
+    }
+
+    // ---------- WIDTH SHARING ----------
+    // Compute remaining parent width and distribute it across columns by
+    // their weight, then lock columns to Fixed(intrinsic + share).
+    let content_available = (available.width.min(max_width).max(config.min_width)
+        - metrics.gutter
+        - config.padding_x * 2.0
+        - spacing_x * columns.saturating_sub(1) as f32)
+        .max(0.0);
+
+    // An autofit request resizes every column to its intrinsic width
+    let autofit = metrics.autofit == Reset::Pending;
+
+    if autofit || metrics.reset == Reset::Pending {
+        metrics.resized.clear();
+
+        if metrics.reset == Reset::Pending {
+            metrics.reset = Reset::Done;
+        }
+    }
+
+    // Resized, fixed and percentage columns keep their width and take no share
+    let resized = |column: usize| {
+        metrics
+            .resized
+            .get(column)
+            .copied()
+            .flatten()
+            .or(sizings[column].fixed)
+            .or_else(|| {
+                sizings[column]
+                    .percent
+                    .filter(|_| !autofit)
+                    .map(|percent| config.snap_up(content_available * percent / 100.0))
+            })
+    };
+
+    for (column, width) in metrics.columns.iter_mut().enumerate() {
+        if let Some(resized) = resized(column) {
+            *width = resized;
+        }
+    }
+
+    let weights: f32 = (0..columns)
+        .filter(|column| resized(*column).is_none())
+        .map(|column| sizings[column].weight)
+        .sum();
+
+    if autofit {
+        for width in &mut metrics.columns {
+            *width = config.snap_up(*width);
+        }
+    }
+
+    let content_intrinsic: f32 = metrics.columns.iter().copied().sum::<f32>();
+    let remaining = (content_available - content_intrinsic).max(0.0);
+
+    // Columns too wide for the available width may give some of it up,
+    // starting with the highest shrink priority
+    if config.shrink && !autofit && content_intrinsic > content_available {
+        let mut priorities: Vec<_> = sizings.iter().map(|sizing| sizing.priority).collect();
+        priorities.sort_unstable_by(|a, b| b.cmp(a));
+        priorities.dedup();
+
+        let mut excess = content_intrinsic - content_available;
+
+        for priority in priorities {
+            let shrinkable: Vec<_> = (0..columns)
+                .map(|column| resized(column).is_none() && sizings[column].priority == priority)
+                .collect();
+
+            excess = shrink(&mut metrics.columns, &headers, &shrinkable, excess);
+        }
+    }
+
+    let share = if weights <= 0.0 || autofit {
+        0.0
+    } else {
+        remaining / weights
+    };
+
+    // Every fluid column takes a share per unit of weight
+    for (column, width) in metrics.columns.iter_mut().enumerate() {
+        if resized(column).is_none() {
+            *width += share * sizings[column].weight;
+        }
+    }
+
+    if autofit {
+        metrics.resized = metrics.columns.iter().copied().map(Some).collect();
+        metrics.autofitted = (0..columns)
+            .filter(|&column| previous.get(column) != Some(&metrics.columns[column]))
+            .collect();
+        metrics.autofit = Reset::Done;
+    }
+
+    let fixed_widths = metrics.columns.clone();
+
+    // ---------- SECOND PASS ----------
+    // Height logic (row factors & distribution) is unchanged.
+    let left_height = available.height - total_fluid_height;
+    let height_unit = if total_row_factors == 0 {
+        0.0
+    } else {
+        (left_height - spacing_y * rows.saturating_sub(1) as f32 - config.padding_y * 2.0)
+            / total_row_factors as f32
+    };
+
+    // Other rows are measured again in the final widths of their columns, so
+    // wrapped contents take the height they need
+    for (row, height) in metrics.rows.iter_mut().enumerate() {
+        if !fluid_rows[row] {
+            *height = 0.0;
+        }
+    }
+
+    // Resized rows override the measured heights
+    for (height, resized) in metrics.rows.iter_mut().zip(&metrics.rows_resized) {
+        if let Some(resized) = resized {
+            *height = *resized;
+        }
+    }
+
+    let mut x = left;
+    let mut y = config.padding_y;
+
+    for (i, size_req) in sizes.iter().enumerate() {
+        let row = i / columns;
+        let column = i % columns;
+
+        if column == 0 {
+            x = left;
+
+            if row > 0 {
+                y += metrics.rows[row - 1] + spacing_y;
+            }
+        }
+
+        let height_factor = size_req.height.fill_factor();
+        let resized = metrics.rows_resized.get(row).copied().flatten();
+
+        let max_height = if let Some(resized) = resized {
+            resized
+        } else if height_factor == 0 {
+            if size_req.height.is_fill() {
+                metrics.rows[row]
+            } else {
+                (available.height - y).max(0.0)
+            }
+        } else {
+            height_unit * height_factor as f32
+        };
+
+        // Force column width to Fixed(intrinsic + share)
+        let fixed = Length::Fixed(fixed_widths[column]);
+
+        let pass2_limits =
+            layout::Limits::new(Size::ZERO, Size::new(available.width - x, max_height))
+                .width(fixed);
+
+        let size = layout(i, &pass2_limits);
+        let sz = pass2_limits.resolve(fixed, Length::Shrink, size);
+
+        // Row metric grows as usual, unless resized
+        if resized.is_none() {
+            metrics.rows[row] = metrics.rows[row].max(sz.height);
+        }
+
+        x += fixed_widths[column] + spacing_x;
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Table<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: R + 'a,
+{
+    fn from(table: Table<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(table)
+    }
+}
+
+/// Produces a [`Task`] that resizes every column of the [`Table`] with the given
+/// [`Id`] to the intrinsic width of its contents.
+///
+/// The columns keep these widths like the ones resized by the user, and each
+/// of them whose width changed is emitted through [`Table::on_column_resize`].
+pub fn autofit<T>(id: impl Into<Id>) -> Task<T>
+where
+    T: Send + 'static,
+{
+    struct Autofit {
+        target: Id,
+    }
+
+    impl Operation for Autofit {
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+            operate(self);
+        }
+
+        fn custom(&mut self, id: Option<&Id>, _bounds: Rectangle, state: &mut dyn Any) {
+            if id == Some(&self.target)
+                && let Some(metrics) = state.downcast_mut::<Metrics>()
+            {
+                metrics.autofit = Reset::Pending;
+            }
+        }
+    }
+
+    operate(Autofit { target: id.into() }).discard()
+}
+
+/// Produces a [`Task`] that drops the widths of the columns resized by the user
+/// in the [`Table`] with the given [`Id`].
+///
+/// The columns return to the fluid layout. The resulting widths are emitted
+/// through [`Table::on_widths_reset`].
+pub fn reset_widths<T>(id: impl Into<Id>) -> Task<T>
+where
+    T: Send + 'static,
+{
+    struct ResetWidths {
+        target: Id,
+    }
+
+    impl Operation for ResetWidths {
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+            operate(self);
+        }
+
+        fn custom(&mut self, id: Option<&Id>, _bounds: Rectangle, state: &mut dyn Any) {
+            if id == Some(&self.target)
+                && let Some(metrics) = state.downcast_mut::<Metrics>()
+            {
+                metrics.reset = Reset::Pending;
+            }
+        }
+    }
+
+    operate(ResetWidths { target: id.into() }).discard()
+}
+
+/// A column of a [`Table`], as addressed by [`cell_id`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CellColumn {
+    /// The index of the column, as given on construction.
+    Index(usize),
+    /// The hashed key given to [`Column::id`]. See [`CellColumn::key`].
+    Key(u64),
+}
+
+impl CellColumn {
+    /// Addresses the column with the given key, as given to [`Column::id`].
+    pub fn key(key: impl Hash) -> Self {
+        Self::Key(hash(key))
+    }
+}
+
+impl From<usize> for CellColumn {
+    fn from(index: usize) -> Self {
+        Self::Index(index)
+    }
+}
+
+/// Returns the [`Id`] of the cell at the given row and column of a [`Table`].
+///
+/// The row is the index of a row of the body. Every column can be addressed
+/// by its index as given on construction, regardless of the order or the
+/// visibility of the columns; a column with a [`Column::id`] can be addressed
+/// by its key too.
+pub fn cell_id(row: usize, column: impl Into<CellColumn>) -> Id {
+    match column.into() {
+        CellColumn::Index(index) => Id::from(format!("iced_table_fluid::cell({row}, #{index})")),
+        CellColumn::Key(key) => Id::from(format!("iced_table_fluid::cell({row}, {key:x})")),
+    }
+}
+
+/// Produces a [`Task`] that applies the given [`Operation`] to the contents
+/// of a cell of the [`Table`] with the given [`Id`].
+///
+/// Useful to focus an input of a specific row, for instance. See [`cell_id`].
+pub fn operate_cell<T>(
+    table: impl Into<Id>,
+    row: usize,
+    column: impl Into<CellColumn>,
+    operation: impl Operation<T> + 'static,
+) -> Task<T>
+where
+    T: Send + 'static,
+{
+    operate(operation::scope(
+        table.into(),
+        operation::scope(cell_id(row, column), operation),
+    ))
+}
+
+fn hash(value: impl Hash) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+
+    hasher.finish()
+}
+
+/// A vertical visualization of some data with a header.
+pub struct Column<'a, 'b, T, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    header: Element<'a, Message, Theme, Renderer>,
+    view: View<'a, 'b, T, Message, Theme, Renderer>,
+    value: Option<Accessor<'b, T, Value>>,
+    format: Format,
+    width: Length,
+    align_x: alignment::Horizontal,
+    align_y: alignment::Vertical,
+    header_align_x: Option<alignment::Horizontal>,
+    header_align_y: Option<alignment::Vertical>,
+    statistics: Option<Statistics_<'a, 'b, T, Message, Theme, Renderer>>,
+    summary: Option<(Aggregate, Summary<'a, Message, Theme, Renderer>)>,
+    delta: Option<Delta<'a, 'b, T, Message, Theme, Renderer>>,
+    computed: Option<Computed<'b, T>>,
+    header_tooltip: Option<Decorator<'a, Message, Theme, Renderer>>,
+    missing: Option<Placeholder<'a, Message, Theme, Renderer>>,
+    title: Option<String>,
+    export: Option<(String, Accessor<'b, T, String>)>,
+    on_press: Option<Accessor<'b, T, Press<'a, Message>>>,
+    id: Option<u64>,
+    percent: Option<f32>,
+    weight: Option<f32>,
+    shrink_priority: u8,
+    fixed: Option<f32>,
+    handle: bool,
+    hover: bool,
+    draggable: bool,
+    hidden: bool,
+    group: Option<Group<'a, Message, Theme, Renderer>>,
+}
+
+type Group<'a, Message, Theme, Renderer> =
+    Rc<RefCell<Option<Element<'a, Message, Theme, Renderer>>>>;
+
+type View<'a, 'b, T, Message, Theme, Renderer> =
+    Box<dyn Fn(T, &Format) -> Option<Cell<'a, Message, Theme, Renderer>> + 'b>;
+
+type Decorator<'a, Message, Theme, Renderer> = Box<
+    dyn FnOnce(Element<'a, Message, Theme, Renderer>) -> Element<'a, Message, Theme, Renderer> + 'a,
+>;
+
+type Press<'a, Message> = Box<dyn Fn() -> Message + 'a>;
+
+struct Computed<'b, T> {
+    value: Compute<'b, T>,
+    context: Rc<RefCell<Context>>,
+}
+
+type Compute<'b, T> = Rc<dyn Fn(&T, &Context) -> Value + 'b>;
+
+type Delta<'a, 'b, T, Message, Theme, Renderer> =
+    Box<dyn Fn(&T, &T) -> Cell<'a, Message, Theme, Renderer> + 'b>;
+
+type Summary<'a, Message, Theme, Renderer> = Box<
+    dyn FnOnce(
+            Element<'a, Message, Theme, Renderer>,
+            String,
+            &Scale,
+            alignment::Horizontal,
+        ) -> Element<'a, Message, Theme, Renderer>
+        + 'a,
+>;
+
+type Placeholder<'a, Message, Theme, Renderer> =
+    Box<dyn Fn(&Format) -> Element<'a, Message, Theme, Renderer> + 'a>;
+
+/// The contents of a single cell of a [`Table`].
+///
+/// Created with the [`cell()`] function.
+pub struct Cell<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    align_x: Option<alignment::Horizontal>,
+    align_y: Option<alignment::Vertical>,
+    span: usize,
+    row_span: usize,
+}
+
+impl<'a, Message, Theme, Renderer> Cell<'a, Message, Theme, Renderer> {
+    /// Sets the alignment for the horizontal axis of the [`Cell`], overriding
+    /// the alignment of its [`Column`].
+    pub fn align_x(mut self, alignment: impl Into<alignment::Horizontal>) -> Self {
+        self.align_x = Some(alignment.into());
+        self
+    }
+
+    /// Sets the alignment for the vertical axis of the [`Cell`], overriding
+    /// the alignment of its [`Column`].
+    pub fn align_y(mut self, alignment: impl Into<alignment::Vertical>) -> Self {
+        self.align_y = Some(alignment.into());
+        self
+    }
+
+    /// Sets the amount of columns the [`Cell`] spans, starting at its own.
+    ///
+    /// The cells it covers are not shown. The span is clamped to the columns
+    /// left in the row; use `usize::MAX` for a cell spanning the rest of it.
+    pub fn span(mut self, columns: usize) -> Self {
+        self.span = columns.max(1);
+        self
+    }
+
+    /// Sets the amount of rows the [`Cell`] spans, starting at its own.
+    ///
+    /// The cells it covers are not shown, and the spanned rows grow to fit
+    /// its contents. The span is clamped to the rows left in the [`Table`].
+    pub fn row_span(mut self, rows: usize) -> Self {
+        self.row_span = rows.max(1);
+        self
+    }
+}
+
+impl<'a, E, Message, Theme, Renderer> From<E> for Cell<'a, Message, Theme, Renderer>
+where
+    E: Into<Element<'a, Message, Theme, Renderer>>,
+{
+    fn from(content: E) -> Self {
+        cell(content)
+    }
+}
+
+struct Statistics_<'a, 'b, T, Message, Theme, Renderer> {
+    value: Accessor<'b, T, Option<f64>>,
+    tooltip: Tooltip<'a, 'b, Statistics, Message, Theme, Renderer>,
+}
+
+type Accessor<'b, T, V> = Box<dyn Fn(&T) -> V + 'b>;
+
+type Tooltip<'a, 'b, T, Message, Theme, Renderer> = Box<
+    dyn FnOnce(Element<'a, Message, Theme, Renderer>, T) -> Element<'a, Message, Theme, Renderer>
+        + 'b,
+>;
+
+impl<'a, 'b, T, Message, Theme, Renderer> Column<'a, 'b, T, Message, Theme, Renderer> {
+    fn new(
+        header: impl Into<Element<'a, Message, Theme, Renderer>>,
+        view: View<'a, 'b, T, Message, Theme, Renderer>,
+    ) -> Self {
+        Self {
+            header: header.into(),
+            view,
+            value: None,
+            format: Format::default(),
+            width: Length::Shrink,
+            align_x: alignment::Horizontal::Left,
+            align_y: alignment::Vertical::Top,
+            header_align_x: None,
+            header_align_y: None,
+            statistics: None,
+            summary: None,
+            delta: None,
+            computed: None,
+            header_tooltip: None,
+            missing: None,
+            title: None,
+            export: None,
+            on_press: None,
+            id: None,
+            percent: None,
+            weight: None,
+            shrink_priority: 0,
+            fixed: None,
+            handle: false,
+            hover: false,
+            draggable: false,
+            hidden: false,
+            group: None,
+        }
+    }
+
+    /// Sets the identity of the [`Column`].
+    ///
+    /// When the columns of a [`Table`] change between views, the state of the
+    /// cells and the resized width follow the [`Column`] with the same id
+    /// instead of its position. Its cells can be addressed by the same key
+    /// with [`CellColumn::key`].
+    pub fn id(mut self, id: impl Hash) -> Self {
+        self.id = Some(hash(id));
+        self
+    }
+
+    /// Sets the width of the [`Column`].
+    ///
+    /// The remaining width of the [`Table`] is shared among its columns in
+    /// proportion to their [`Length::FillPortion`]. Any other width takes a
+    /// single share.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the weight of the [`Column`] when sharing the remaining width of
+    /// the [`Table`], overriding its [`Length::FillPortion`].
+    ///
+    /// A column with a weight of 3 receives three times the extra width of a
+    /// column with a weight of 1. Its intrinsic width is measured as usual.
+    pub fn fluid_weight(mut self, weight: f32) -> Self {
+        self.weight = Some(weight.max(0.0));
+        self
+    }
+
+    /// Sets the shrink priority of the [`Column`] when the [`Table`] shrinks
+    /// its columns to fit. See [`Table::shrink_to_fit`].
+    ///
+    /// Columns with a higher priority give up their width first; columns with
+    /// the same priority shrink in proportion to their widths. Defaults to 0.
+    pub fn shrink_priority(mut self, priority: u8) -> Self {
+        self.shrink_priority = priority;
+        self
+    }
+
+    /// Keeps the [`Column`] at the intrinsic width of its contents, leaving
+    /// the remaining width of the [`Table`] to the other columns.
+    pub fn auto_fit(mut self) -> Self {
+        self.weight = Some(0.0);
+        self
+    }
+
+    /// Fixes the width of the [`Column`], leaving the remaining width of the
+    /// [`Table`] to the other columns.
+    ///
+    /// A column resized by the user keeps the resized width instead.
+    pub fn fixed(mut self, width: impl Into<Pixels>) -> Self {
+        let width = width.into().0.max(0.0);
+
+        self.width = Length::Fixed(width);
+        self.fixed = Some(width);
+        self
+    }
+
+    /// Sets the width of the [`Column`] as a percentage of the width available
+    /// to the contents of the [`Table`], after padding and separators.
+    ///
+    /// The [`Table`] fills the available width. A column resized by the user
+    /// keeps the resized width instead.
+    pub fn width_percent(mut self, percent: f32) -> Self {
+        self.width = Length::Fill;
+        self.percent = Some(percent.max(0.0));
+        self
+    }
+
+    /// Only shows the cells of the [`Column`] on the hovered row.
+    ///
+    /// Useful for row actions, like edit or delete buttons, that would
+    /// otherwise clutter the [`Table`]. Hidden cells keep their space.
+    pub fn show_on_hover(mut self) -> Self {
+        self.hover = true;
+        self
+    }
+
+    /// Sets whether the [`Column`] is hidden.
+    ///
+    /// Hidden columns are left out of the [`Table`] entirely, so the column
+    /// definitions can stay the same while toggling their visibility. The
+    /// other columns keep their index as given on construction, and their
+    /// state across toggles.
+    pub fn hidden(mut self, hidden: bool) -> Self {
+        self.hidden = hidden;
+        self
+    }
+
+    /// Makes the cells of the [`Column`] drag sources and drop targets.
+    ///
+    /// Dropping a cell on another one produces the message of
+    /// [`Table::on_cell_drop`]. The contents of draggable cells are not
+    /// pressed.
+    ///
+    /// Drags are local to a single [`Table`]; cells cannot be dropped on
+    /// the cells of another one.
+    pub fn draggable(mut self) -> Self {
+        self.draggable = true;
+        self
+    }
+
+    /// Sets the alignment for the horizontal axis of the [`Column`].
+    pub fn align_x(mut self, alignment: impl Into<alignment::Horizontal>) -> Self {
+        self.align_x = alignment.into();
+        self
+    }
+
+    /// Sets the alignment for the vertical axis of the [`Column`].
+    pub fn align_y(mut self, alignment: impl Into<alignment::Vertical>) -> Self {
+        self.align_y = alignment.into();
+        self
+    }
+
+    /// Sets the alignment for the horizontal axis of the header of the
+    /// [`Column`], overriding the alignment of the [`Column`].
+    pub fn header_align_x(mut self, alignment: impl Into<alignment::Horizontal>) -> Self {
+        self.header_align_x = Some(alignment.into());
+        self
+    }
+
+    /// Sets the alignment for the vertical axis of the header of the
+    /// [`Column`], overriding the alignment of the [`Column`].
+    pub fn header_align_y(mut self, alignment: impl Into<alignment::Vertical>) -> Self {
+        self.header_align_y = Some(alignment.into());
+        self
+    }
+
+    /// Sets the amount of decimal places of the values of a [`numeric`] [`Column`].
+    pub fn decimals(mut self, decimals: usize) -> Self {
+        self.format.decimals = Some(decimals);
+        self
+    }
+
+    /// Picks the amount of decimal places of a [`numeric`] [`Column`] from the
+    /// magnitudes of its values, so every row shows the same amount.
+    ///
+    /// Values too large or too small for it are shown in scientific notation.
+    /// Only the first rows are sampled on large tables.
+    pub fn auto_precision(mut self) -> Self {
+        self.format.auto = true;
+        self
+    }
+
+    /// Lines up the values of a [`numeric`] [`Column`] on their decimal separator.
+    ///
+    /// The fractional digits of every value are padded to the longest fraction
+    /// in the [`Column`], and the values are aligned to the right. Without
+    /// [`Column::decimals`], the fractions are rounded like the ones picked by
+    /// [`Column::auto_precision`], so float noise does not pad the others.
+    pub fn align_decimal(mut self) -> Self {
+        self.format.align_decimal = true;
+        self.align_x = alignment::Horizontal::Right;
+        self
+    }
+
+    /// Computes the [`Statistics`] of the numeric values of the [`Column`] and
+    /// shows them in a tooltip when hovering its header.
+    ///
+    /// Rows producing `None` are left out of the [`Statistics`]. The `view`
+    /// function produces the contents of the tooltip.
+    pub fn statistics<E>(
+        mut self,
+        value: impl Fn(&T) -> Option<f64> + 'b,
+        view: impl Fn(Statistics) -> E + 'b,
+    ) -> Self
+    where
+        E: Into<Element<'a, Message, Theme, Renderer>>,
+        Message: 'a,
+        Theme: container::Catalog + 'a,
+        Renderer: text::Renderer + 'a,
+    {
+        self.statistics = Some(Statistics_ {
+            value: Box::new(value),
+            tooltip: Box::new(move |header, statistics| {
+                tooltip(header, view(statistics), tooltip::Position::Bottom).into()
+            }),
+        });
+        self
+    }
+
+    /// Shows the difference of every row with the baseline row, instead of its
+    /// value.
+    ///
+    /// The closure receives the row and the baseline. Only used by tables
+    /// created with [`baseline()`].
+    pub fn delta<E>(mut self, delta: impl Fn(&T, &T) -> E + 'b) -> Self
+    where
+        E: Into<Cell<'a, Message, Theme, Renderer>>,
+    {
+        self.delta = Some(Box::new(move |row, baseline| delta(row, baseline).into()));
+        self
+    }
+
+    /// Shows the given [`Aggregate`] of the values of the [`Column`] in a thin
+    /// row below its header.
+    ///
+    /// Numeric aggregates use the [`Column::value`] and the formatting of the
+    /// [`Column`].
+    pub fn summary(mut self, aggregate: Aggregate) -> Self
+    where
+        Message: 'a,
+        Theme: widget::text::Catalog + 'a,
+        Renderer: text::Renderer + 'a,
+    {
+        self.summary = Some((
+            aggregate,
+            Box::new(|header, summary, scale, align_x| {
+                let summary = Element::new(Dimmed {
+                    content: Label::new(summary, 0.75, Wrap::Word, scale).into(),
+                });
+
+                widget::column![header, summary].align_x(align_x).into()
+            }),
+        ));
+        self
+    }
+
+    /// Sets the tooltip shown when hovering the header of the [`Column`].
+    ///
+    /// Useful to describe abbreviated titles without widening the header.
+    pub fn header_tooltip(
+        mut self,
+        tooltip: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self
+    where
+        Message: 'a,
+        Theme: container::Catalog + 'a,
+        Renderer: text::Renderer + 'a,
+    {
+        let content = tooltip.into();
+
+        self.header_tooltip = Some(Box::new(move |header| {
+            widget::tooltip(header, content, tooltip::Position::Top).into()
+        }));
+        self
+    }
+
+    /// Sets the [`Missing`] policy of the [`Column`], used to render the rows
+    /// without contents.
+    ///
+    /// By default, missing values are left blank.
+    pub fn on_missing(mut self, missing: Missing<'a, Message, Theme, Renderer>) -> Self
+    where
+        Message: 'a,
+        Theme: widget::text::Catalog + 'a,
+        Renderer: text::Renderer + 'a,
+    {
+        self.missing = Some(Box::new(move |format| match &missing {
+            Missing::Blank => Space::new().into(),
+            Missing::Dash => format.label(String::from("\u{2014}")).into(),
+            Missing::Text(content) => format.label((*content).to_owned()).into(),
+            Missing::Element(view) => view(),
+        }));
+        self
+    }
+
+    /// Sets the message that should be produced when a cell of the [`Column`]
+    /// is pressed.
+    ///
+    /// The row of the cell is provided to the closure.
+    pub fn on_press(mut self, on_press: impl Fn(&T) -> Message + 'b) -> Self
+    where
+        Message: Clone + 'a,
+    {
+        self.on_press = Some(Box::new(move |row| {
+            let message = on_press(row);
+
+            Box::new(move || message.clone())
+        }));
+        self
+    }
+
+    /// Sets the canonical [`Value`] of the cells of the [`Column`], independent
+    /// of how they are displayed.
+    ///
+    /// Numeric values drive the formatting of [`numeric`] columns.
+    pub fn value(mut self, value: impl Fn(&T) -> Value + 'b) -> Self {
+        self.value = Some(Box::new(value));
+        self
+    }
+
+    /// Sets the textual title of the [`Column`], used as its header when
+    /// copying its [`value`](Self::value) to the clipboard.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Sets the header and the value function used when copying the [`Table`]
+    /// to the clipboard.
+    ///
+    /// Pressing Ctrl+C while hovering the [`Table`] copies all of its rows as
+    /// tab-separated values, unless a cell captures the key press first.
+    /// Only the columns with an export or a [`value`](Self::value) are copied;
+    /// the latter under their [`title`](Self::title).
+    pub fn export(mut self, header: impl Into<String>, value: impl Fn(&T) -> String + 'b) -> Self {
+        self.export = Some((header.into(), Box::new(value)));
+        self
+    }
+
+    /// Sets the [`Wrap`] mode of the text in the cells of the [`Column`].
+    ///
+    /// The text produced by the [`Table`], like the values of a [`numeric`]
+    /// [`Column`], follows it. With [`Wrap::None`], any contents are kept in a
+    /// single line and cut at the width of the [`Column`].
+    pub fn wrap(mut self, wrap: Wrap) -> Self
+    where
+        'a: 'b,
+        T: 'b,
+        Message: 'a,
+        Theme: 'a,
+        Renderer: R + 'a,
+    {
+        self.format.wrap = wrap;
+
+        if wrap == Wrap::None {
+            let view = self.view;
+
+            self.view = Box::new(move |data, format| {
+                view(data, format).map(|cell| Cell {
+                    content: Element::new(Truncate {
+                        content: cell.content,
+                        ellipsis: None,
+                    }),
+                    ..cell
+                })
+            });
+        }
+
+        self
+    }
+
+    /// Cuts the contents of the cells of the [`Column`] with an ellipsis when
+    /// they do not fit its width, instead of wrapping them.
+    pub fn truncate(mut self) -> Self
+    where
+        'a: 'b,
+        T: 'b,
+        Message: 'a,
+        Theme: widget::text::Catalog + 'a,
+        Renderer: text::Renderer + 'a,
+    {
+        let view = self.view;
+
+        self.view = Box::new(move |data, format| {
+            view(data, format).map(|cell| Cell {
+                content: Element::new(Truncate {
+                    content: cell.content,
+                    ellipsis: Some(Element::new(Label::new(
+                        String::from("\u{2026}"),
+                        1.0,
+                        Wrap::None,
+                        &format.scale,
+                    ))),
+                }),
+                ..cell
+            })
+        });
+        self
+    }
+
+    /// Attaches a unit to the values of the [`Column`], placed at the given
+    /// [`Position`].
+    ///
+    /// The unit is rendered in a dimmer color than the value itself.
+    pub fn unit(mut self, unit: impl Into<String>, position: Position) -> Self
+    where
+        'a: 'b,
+        T: 'b,
+        Message: 'a,
+        Theme: widget::text::Catalog + 'a,
+        Renderer: text::Renderer + 'a,
+    {
+        let unit = unit.into();
+        let view = self.view;
+
+        self.view = Box::new(move |data, format| {
+            view(data, format).map(|cell| {
+                let unit = Element::new(Dimmed {
+                    content: Label::new(unit.clone(), 1.0, Wrap::None, &format.scale).into(),
+                });
+
+                let content = match position {
+                    Position::Prefix => widget::row![unit, cell.content],
+                    Position::Suffix => widget::row![cell.content, unit],
+                };
+
+                Cell {
+                    content: content.align_y(Alignment::Center).into(),
+                    ..cell
+                }
+            })
+        });
+        self
+    }
+}
+
+/// The canonical data of a cell of a [`Column`].
+///
+/// See [`Column::value`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    /// Some text.
+    Text(String),
+    /// A number.
+    Number(f64),
+    /// A boolean.
+    Bool(bool),
+    /// A calendar date.
+    Date {
+        /// The year of the date.
+        year: i32,
+        /// The month of the date, from 1 to 12.
+        month: u8,
+        /// The day of the date, from 1 to 31.
+        day: u8,
+    },
+}
+
+impl Value {
+    fn fingerprint(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.to_string().hash(&mut hasher);
+
+        hasher.finish()
+    }
+
+    fn number(&self) -> Option<f64> {
+        match self {
+            Self::Number(number) => Some(*number),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Text(text) => f.write_str(text),
+            Self::Number(number) => write!(f, "{number}"),
+            Self::Bool(bool) => write!(f, "{bool}"),
+            Self::Date { year, month, day } => write!(f, "{year:04}-{month:02}-{day:02}"),
+        }
+    }
+}
+
+impl From<String> for Value {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_owned())
+    }
+}
+
+impl From<f64> for Value {
+    fn from(number: f64) -> Self {
+        Self::Number(number)
+    }
+}
+
+impl From<bool> for Value {
+    fn from(bool: bool) -> Self {
+        Self::Bool(bool)
+    }
+}
+
+/// The position of the unit of a [`Column`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Position {
+    /// The unit is placed before the value.
+    Prefix,
+    /// The unit is placed after the value.
+    Suffix,
+}
+
+/// The horizontal scrollbar of a [`Table`].
+///
+/// It stays at the bottom of the visible area when the [`Table`] is taller
+/// than its viewport.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Scrollbar {
+    /// The thickness of the scrollbar.
+    pub width: f32,
+    /// The space around the scrollbar.
+    pub margin: f32,
+    /// The [`Placement`] of the scrollbar.
+    pub placement: Placement,
+}
+
+impl Default for Scrollbar {
+    fn default() -> Self {
+        Self {
+            width: 6.0,
+            margin: 0.0,
+            placement: Placement::default(),
+        }
+    }
+}
+
+/// The placement of the [`Scrollbar`] of a [`Table`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Placement {
+    /// The scrollbar is drawn on top of the last row.
+    #[default]
+    Overlay,
+    /// The scrollbar takes its own space below the last row.
+    Reserved,
+}
+
+/// The saved layout of a [`Table`], owned by the application.
+///
+/// The widths are indexed by the columns as given on construction. Enable
+/// the `serde` feature to persist it between sessions. See [`Table::state`].
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State {
+    widths: Vec<Option<f32>>,
+}
+
+impl State {
+    /// Creates an empty [`State`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a [`State`] with the given column widths, e.g. loaded from
+    /// disk. Columns without a width are laid out as usual.
+    pub fn from_widths(widths: impl IntoIterator<Item = Option<f32>>) -> Self {
+        Self {
+            widths: widths.into_iter().collect(),
+        }
+    }
+
+    /// Returns the saved widths of the columns, to be persisted.
+    pub fn widths(&self) -> &[Option<f32>] {
+        &self.widths
+    }
+
+    /// Saves the width of the column with the given index.
+    pub fn resize(&mut self, column: usize, width: f32) {
+        if self.widths.len() <= column {
+            self.widths.resize(column + 1, None);
+        }
+
+        self.widths[column] = Some(width);
+    }
+
+    /// Forgets the saved widths of every column.
+    pub fn reset(&mut self) {
+        self.widths.clear();
+    }
+}
+
+/// The location of a body cell of a [`Table`].
+///
+/// See [`Table::on_cell_drop`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RowColumn {
+    /// The index of the row of the cell.
+    pub row: usize,
+    /// The index of the column of the cell, as given on construction.
+    pub column: usize,
+}
+
+/// A line separator between the cells of a [`Table`].
+///
+/// See [`Table::on_separator_click`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Separator {
+    /// The vertical separator to the right of the column with the given index,
+    /// as given on construction.
+    Column(usize),
+    /// The horizontal separator below the row with the given index.
+    Row(usize),
+}
+
+/// The accessibility settings of a [`Table`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Accessibility {
+    /// Draws separators and indicators with the color of the text, for
+    /// maximum contrast.
+    pub high_contrast: bool,
+    /// Disables the animations of the [`Table`].
+    pub reduced_motion: bool,
+}
+
+/// The rendering policy of the missing values of a [`Column`].
+pub enum Missing<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    /// Leaves the cell empty.
+    Blank,
+    /// Renders an em dash.
+    Dash,
+    /// Renders the given text.
+    Text(&'a str),
+    /// Renders the element produced by the given function.
+    Element(fn() -> Element<'a, Message, Theme, Renderer>),
+}
+
+/// A renderer of the cells of a [`custom_column`].
+///
+/// Useful for very hot columns that should avoid the overhead of the widgets
+/// of their contents, like text shaping and nested trees. It does not avoid
+/// allocating the cells themselves.
+pub trait CellRenderer<T, Renderer = iced::Renderer> {
+    /// Returns the size of the cell of the given row.
+    fn measure(&self, row: &T, renderer: &Renderer, limits: &layout::Limits) -> Size;
+
+    /// Draws the cell of the given row inside the given bounds.
+    fn draw(&self, row: &T, renderer: &mut Renderer, style: &renderer::Style, bounds: Rectangle);
+}
+
+/// A cell drawn by a [`CellRenderer`].
+struct Rendered<'a, T, Renderer> {
+    data: T,
+    cell_renderer: Rc<dyn CellRenderer<T, Renderer> + 'a>,
+}
+
+impl<T, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Rendered<'_, T, Renderer>
+where
+    Renderer: R,
+{
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Shrink,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut tree::Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::Node::new(self.cell_renderer.measure(&self.data, renderer, limits))
+    }
+
+    fn draw(
+        &self,
+        _tree: &tree::Tree,
+        renderer: &mut Renderer,
+        _theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        self.cell_renderer
+            .draw(&self.data, renderer, style, layout.bounds());
+    }
+}
+
+/// The grip of a [`drag_handle_column`].
+struct Grip;
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Grip
+where
+    Renderer: R,
+{
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Fixed(GRIP_DOT * 3.0),
+            height: Length::Fixed(GRIP_DOT * 5.0),
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut tree::Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, GRIP_DOT * 3.0, GRIP_DOT * 5.0)
+    }
+
+    fn draw(
+        &self,
+        _tree: &tree::Tree,
+        renderer: &mut Renderer,
+        _theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+
+        // Two columns of three dots
+        for column in [0.0, 2.0] {
+            for row in [0.0, 2.0, 4.0] {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: bounds.x + column * GRIP_DOT,
+                            y: bounds.y + row * GRIP_DOT,
+                            width: GRIP_DOT,
+                            height: GRIP_DOT,
+                        },
+                        border: border::rounded(GRIP_DOT / 2.0),
+                        ..renderer::Quad::default()
+                    },
+                    style.text_color.scale_alpha(0.5),
+                );
+            }
+        }
+    }
+}
+
+/// The size of the dots of a [`Grip`].
+const GRIP_DOT: f32 = 3.0;
+
+/// A cell of a [`live_cell`] view.
+struct Live<'a, T, Message, Theme, Renderer> {
+    row: T,
+    view: LiveView<'a, T, Message, Theme, Renderer>,
+    content: Element<'a, Message, Theme, Renderer>,
+    interval: Duration,
+}
+
+type LiveView<'a, T, Message, Theme, Renderer> =
+    Rc<dyn Fn(&T) -> Element<'a, Message, Theme, Renderer> + 'a>;
+
+/// The instant a [`Live`] cell will be rebuilt.
+struct Schedule(Option<Instant>);
+
+impl<T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Live<'_, T, Message, Theme, Renderer>
+where
+    Renderer: R,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<Schedule>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(Schedule(None))
+    }
+
+    fn children(&self) -> Vec<tree::Tree> {
+        vec![tree::Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut tree::Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut tree::Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let content = self
+            .content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits);
+
+        layout::Node::with_children(content.size(), vec![content])
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut tree::Tree,
+        event: &iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn advanced::Clipboard,
+        shell: &mut advanced::Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        if let iced::Event::Window(window::Event::RedrawRequested(now)) = event {
+            let Schedule(next) = tree.state.downcast_mut::<Schedule>();
+
+            let rebuilt = next.is_some_and(|next| *now >= next);
+
+            if next.is_none_or(|next| *now >= next) {
+                *next = Some(*now + self.interval);
+            }
+
+            if let Some(next) = *next {
+                shell.request_redraw_at(window::RedrawRequest::At(next));
+            }
+
+            // The layout of the rebuilt content is stale until the next one
+            if rebuilt {
+                self.content = (self.view)(&self.row);
+                tree.children[0].diff(&self.content);
+                shell.invalidate_layout();
+                return;
+            }
+        }
+
+        if let Some(layout) = layout.children().next() {
+            self.content.as_widget_mut().update(
+                &mut tree.children[0],
+                event,
+                layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &tree::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        if let Some(layout) = layout.children().next() {
+            self.content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                layout,
+                cursor,
+                viewport,
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &tree::Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        layout
+            .children()
+            .next()
+            .map(|layout| {
+                self.content.as_widget().mouse_interaction(
+                    &tree.children[0],
+                    layout,
+                    cursor,
+                    viewport,
+                    renderer,
+                )
+            })
+            .unwrap_or_default()
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut tree::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        if let Some(layout) = layout.children().next() {
+            self.content.as_widget_mut().operate(
+                &mut tree.children[0],
+                layout,
+                renderer,
+                operation,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut tree::Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next()?,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+/// Lays out its contents in a single line, cutting them when they do not
+/// fit, with an ellipsis if any.
+struct Truncate<'a, Message, Theme, Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    ellipsis: Option<Element<'a, Message, Theme, Renderer>>,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Truncate<'_, Message, Theme, Renderer>
+where
+    Renderer: R,
+{
+    fn children(&self) -> Vec<tree::Tree> {
+        std::iter::once(&self.content)
+            .chain(&self.ellipsis)
+            .map(tree::Tree::new)
             .collect()
     }
 
-    fn diff(&self, state: &mut tree::Tree) {
-        state.diff_children(&self.cells);
+    fn diff(&self, tree: &mut tree::Tree) {
+        let children: Vec<_> = std::iter::once(&self.content)
+            .chain(&self.ellipsis)
+            .collect();
+
+        tree.diff_children(&children);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
     }
 
     fn layout(
@@ -236,403 +5480,779 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        let metrics = tree.state.downcast_mut::<Metrics>();
-        let columns = self.columns.len();
-        let rows = self.cells.len() / columns;
+        let max = limits.max();
 
-        let limits = limits.width(self.width).height(self.height);
-        let available = limits.max();
-        let max_limits = limits.width(self.max_width).height(self.height).max();
+        let content = self.content.as_widget_mut().layout(
+            &mut tree.children[0],
+            renderer,
+            &layout::Limits::new(Size::ZERO, Size::new(f32::INFINITY, max.height)),
+        );
 
-        let mut cells = Vec::with_capacity(self.cells.len());
-        cells.resize(self.cells.len(), layout::Node::default());
+        let size = limits.resolve(Length::Shrink, Length::Shrink, content.size());
+        let mut children = vec![content];
 
-        metrics.columns = vec![0.0; columns];
-        metrics.rows = vec![0.0; rows];
+        if let Some(ellipsis) = &mut self.ellipsis {
+            let ellipsis = ellipsis.as_widget_mut().layout(
+                &mut tree.children[1],
+                renderer,
+                &layout::Limits::new(Size::ZERO, max),
+            );
 
-        // We keep row height logic (factors & distribution) intact
-        let mut total_row_factors = 0;
-        let mut total_fluid_height = 0.0;
-        let mut row_factor = 0;
+            let offset = size - ellipsis.size();
+            children.push(ellipsis.move_to((offset.width.max(0.0), offset.height / 2.0)));
+        }
 
-        // spacing_x includes per-column left+right padding plus the separator
-        let spacing_x = self.padding_x * 2.0 + self.separator_x;
-        let spacing_y = self.padding_y * 2.0 + self.separator_y;
+        layout::Node::with_children(size, children)
+    }
 
-        // ---------- FIRST PASS ----------
-        // Ignore declared column widths: treat as Shrink to measure intrinsic widths per column.
-        let mut x = self.padding_x;
-        let mut y = self.padding_y;
+    fn update(
+        &mut self,
+        tree: &mut tree::Tree,
+        event: &iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn advanced::Clipboard,
+        shell: &mut advanced::Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        if let Some(layout) = layout.children().next() {
+            self.content.as_widget_mut().update(
+                &mut tree.children[0],
+                event,
+                layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        }
+    }
 
-        for (i, (cell, state)) in self.cells.iter_mut().zip(&mut tree.children).enumerate() {
-            let row = i / columns;
-            let column = i % columns;
+    fn draw(
+        &self,
+        tree: &tree::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let mut children = layout.children();
 
-            if column == 0 {
-                x = self.padding_x;
+        let Some(content) = children.next() else {
+            return;
+        };
 
-                if row > 0 {
-                    y += metrics.rows[row - 1] + spacing_y;
+        if content.bounds().width <= bounds.width {
+            self.content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                content,
+                cursor,
+                viewport,
+            );
 
-                    if row_factor != 0 {
-                        total_fluid_height += metrics.rows[row - 1];
-                        total_row_factors += row_factor;
-                        row_factor = 0;
-                    }
-                }
-            }
+            return;
+        }
+
+        let ellipsis = self.ellipsis.as_ref().zip(children.next());
+
+        let clip = Rectangle {
+            width: (bounds.width - ellipsis.map_or(0.0, |(_, layout)| layout.bounds().width))
+                .max(0.0),
+            ..bounds
+        };
+
+        if let Some(clip) = clip.intersection(viewport) {
+            renderer.with_layer(clip, |renderer| {
+                self.content.as_widget().draw(
+                    &tree.children[0],
+                    renderer,
+                    theme,
+                    style,
+                    content,
+                    cursor,
+                    &clip,
+                );
+            });
+        }
+
+        if let Some((ellipsis, layout)) = ellipsis {
+            ellipsis.as_widget().draw(
+                &tree.children[1],
+                renderer,
+                theme,
+                style,
+                layout,
+                cursor,
+                viewport,
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &tree::Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        layout
+            .children()
+            .next()
+            .map(|layout| {
+                self.content.as_widget().mouse_interaction(
+                    &tree.children[0],
+                    layout,
+                    cursor,
+                    viewport,
+                    renderer,
+                )
+            })
+            .unwrap_or_default()
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut tree::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        if let Some(layout) = layout.children().next() {
+            self.content.as_widget_mut().operate(
+                &mut tree.children[0],
+                layout,
+                renderer,
+                operation,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut tree::Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next()?,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+/// Draws its contents with a dimmer text color.
+struct Dimmed<'a, Message, Theme, Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Dimmed<'_, Message, Theme, Renderer>
+where
+    Renderer: R,
+{
+    fn tag(&self) -> tree::Tag {
+        self.content.as_widget().tag()
+    }
+
+    fn state(&self) -> tree::State {
+        self.content.as_widget().state()
+    }
+
+    fn children(&self) -> Vec<tree::Tree> {
+        self.content.as_widget().children()
+    }
+
+    fn diff(&self, tree: &mut tree::Tree) {
+        self.content.as_widget().diff(tree);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut tree::Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.as_widget_mut().layout(tree, renderer, limits)
+    }
+
+    fn draw(
+        &self,
+        tree: &tree::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let style = renderer::Style {
+            text_color: style.text_color.scale_alpha(0.6),
+        };
+
+        self.content
+            .as_widget()
+            .draw(tree, renderer, theme, &style, layout, cursor, viewport);
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut tree::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget_mut()
+            .operate(tree, layout, renderer, operation);
+    }
+}
+
+/// The scale of a [`Table`], shared with the [`Label`]s of its cells.
+#[derive(Debug, Clone)]
+struct Scale(Rc<std::cell::Cell<f32>>);
+
+impl Default for Scale {
+    fn default() -> Self {
+        Self(Rc::new(std::cell::Cell::new(1.0)))
+    }
+}
+
+/// Some text produced by a [`Table`], sized with its [`Scale`] on layout.
+struct Label<'a, Theme, Renderer>
+where
+    Theme: widget::text::Catalog,
+    Renderer: text::Renderer,
+{
+    text: widget::Text<'a, Theme, Renderer>,
+    content: String,
+    size: f32,
+    wrap: Wrap,
+    scale: Scale,
+    applied: f32,
+}
+
+impl<'a, Theme, Renderer> Label<'a, Theme, Renderer>
+where
+    Theme: widget::text::Catalog + 'a,
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`Label`] with the given content and size, relative to
+    /// the default text size.
+    fn new(content: String, size: f32, wrap: Wrap, scale: &Scale) -> Self {
+        let applied = scale.0.get();
+
+        Self {
+            text: Self::text(&content, size * applied, wrap),
+            content,
+            size,
+            wrap,
+            scale: scale.clone(),
+            applied,
+        }
+    }
+
+    fn text(content: &str, size: f32, wrap: Wrap) -> widget::Text<'a, Theme, Renderer> {
+        let text = widget::text(content.to_owned()).wrapping(wrap.into());
+
+        if size == 1.0 {
+            text
+        } else {
+            text.size(TEXT_SIZE * size)
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Label<'a, Theme, Renderer>
+where
+    Theme: widget::text::Catalog + 'a,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        Widget::<Message, Theme, Renderer>::tag(&self.text)
+    }
+
+    fn state(&self) -> tree::State {
+        Widget::<Message, Theme, Renderer>::state(&self.text)
+    }
+
+    fn size(&self) -> Size<Length> {
+        Widget::<Message, Theme, Renderer>::size(&self.text)
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut tree::Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let scale = self.scale.0.get();
+
+        if scale != self.applied {
+            self.text = Self::text(&self.content, self.size * scale, self.wrap);
+            self.applied = scale;
+        }
+
+        Widget::<Message, Theme, Renderer>::layout(&mut self.text, tree, renderer, limits)
+    }
+
+    fn draw(
+        &self,
+        tree: &tree::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        Widget::<Message, Theme, Renderer>::draw(
+            &self.text, tree, renderer, theme, style, layout, cursor, viewport,
+        );
+    }
 
-            let size_req = cell.as_widget().size();
-            let height_factor = size_req.height.fill_factor();
-            row_factor = row_factor.max(height_factor);
+    fn operate(
+        &mut self,
+        tree: &mut tree::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        Widget::<Message, Theme, Renderer>::operate(
+            &mut self.text,
+            tree,
+            layout,
+            renderer,
+            operation,
+        );
+    }
+}
 
-            // Layout with width forced to Shrink, so we can measure intrinsic content width.
-            let max = Size::new(available.width - x, available.height - y);
-            let pass1_limits = layout::Limits::new(Size::ZERO, max).width(Length::Shrink);
+impl<'a, Message, Theme, Renderer> From<Label<'a, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Theme: widget::text::Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(label: Label<'a, Theme, Renderer>) -> Self {
+        Element::new(label)
+    }
+}
 
-            let layout = cell.as_widget_mut().layout(state, renderer, &pass1_limits);
-            let sz = pass1_limits.resolve(Length::Shrink, Length::Shrink, layout.size());
+/// The wrapping of the text in the cells of a [`Column`].
+///
+/// See [`Column::wrap`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Wrap {
+    /// The text is kept in a single line.
+    None,
+    /// The text wraps at word boundaries.
+    #[default]
+    Word,
+    /// The text wraps at any character.
+    Glyph,
+}
 
-            // Per-column intrinsic width (content), accumulated as max
-            metrics.columns[column] = metrics.columns[column].max(sz.width);
+impl From<Wrap> for text::Wrapping {
+    fn from(wrap: Wrap) -> Self {
+        match wrap {
+            Wrap::None => Self::None,
+            Wrap::Word => Self::Word,
+            Wrap::Glyph => Self::Glyph,
+        }
+    }
+}
 
-            // Row height metrics only for non-fluid rows (existing behavior preserved)
-            if height_factor == 0 && !size_req.height.is_fill() {
-                metrics.rows[row] = metrics.rows[row].max(sz.height);
-            }
+/// The formatting of the values of a [`numeric`] [`Column`].
+#[derive(Debug, Clone, Default)]
+struct Format {
+    decimals: Option<usize>,
+    align_decimal: bool,
+    fraction: usize,
+    auto: bool,
+    scientific: bool,
+    wrap: Wrap,
+    scale: Scale,
+}
 
-            // Store node for now; it will be re-laid out in pass 2
-            cells[i] = layout;
+impl Format {
+    fn label<'a, Theme, Renderer>(&self, content: String) -> Label<'a, Theme, Renderer>
+    where
+        Theme: widget::text::Catalog + 'a,
+        Renderer: text::Renderer,
+    {
+        Label::new(content, 1.0, self.wrap, &self.scale)
+    }
 
-            x += sz.width + spacing_x;
+    fn resolve(&mut self, values: impl Iterator<Item = f64> + Clone) {
+        if self.auto && self.decimals.is_none() {
+            self.detect(values.clone().take(AUTO_PRECISION_SAMPLE));
         }
 
-        // Account for last row's factors
-        if row_factor != 0 && rows > 0 {
-            total_fluid_height += metrics.rows[rows - 1];
-            total_row_factors += row_factor;
-        }
+        if self.align_decimal && self.decimals.is_none() {
+            let magnitudes: Vec<_> = values
+                .filter(|value| value.is_finite())
+                .map(f64::abs)
+                .collect();
 
-        // ---------- WIDTH SHARING ----------
-        // Compute remaining parent width and distribute evenly across columns,
-        // then lock columns to Fixed(intrinsic + share).
-        let content_available = (available.width.min(max_limits.width)
-            - self.padding_x * 2.0
-            - spacing_x * columns.saturating_sub(1) as f32)
-            .max(0.0);
+            // Float noise is rounded away at the detected precision
+            let precision = precision(&magnitudes);
 
-        let content_intrinsic: f32 = metrics.columns.iter().copied().sum::<f32>();
-        let remaining = (content_available - content_intrinsic).max(0.0);
-        let share = if columns == 0 {
-            0.0
+            self.fraction = magnitudes
+                .iter()
+                .map(|magnitude| fraction(&rounded(*magnitude, precision)))
+                .max()
+                .unwrap_or_default();
         } else {
-            remaining / columns as f32
-        };
-
-        // let mut fixed_widths = vec![0.0; columns];
-        metrics.columns = metrics.columns.iter().map(|v| v + share).collect();
-        let fixed_widths = metrics.columns.clone();
+            self.fraction = self.decimals.unwrap_or_default();
+        }
+    }
 
-        // ---------- SECOND PASS ----------
-        // Height logic (row factors & distribution) is unchanged.
-        let left_height = available.height - total_fluid_height;
-        let height_unit = if total_row_factors == 0 {
-            0.0
-        } else {
-            (left_height - spacing_y * rows.saturating_sub(1) as f32 - self.padding_y * 2.0)
-                / total_row_factors as f32
-        };
+    /// Picks the amount of decimal places fitting the magnitudes of the given
+    /// values, or scientific notation when they are too large or too small.
+    fn detect(&mut self, values: impl Iterator<Item = f64>) {
+        let magnitudes: Vec<_> = values
+            .filter(|value| value.is_finite())
+            .map(f64::abs)
+            .collect();
 
-        let mut x = self.padding_x;
-        let mut y = self.padding_y;
+        let largest = magnitudes.iter().copied().fold(0.0, f64::max);
+        let smallest = magnitudes
+            .iter()
+            .copied()
+            .filter(|magnitude| *magnitude > 0.0)
+            .fold(f64::INFINITY, f64::min);
 
-        for (i, (cell, state)) in self.cells.iter_mut().zip(&mut tree.children).enumerate() {
-            let row = i / columns;
-            let column = i % columns;
+        if largest >= 1e9 || smallest < 1e-4 {
+            self.scientific = true;
+            self.decimals = Some(2);
+            return;
+        }
 
-            if column == 0 {
-                x = self.padding_x;
+        self.decimals = Some(precision(&magnitudes));
+    }
 
-                if row > 0 {
-                    y += metrics.rows[row - 1] + spacing_y;
-                }
-            }
+    fn number(&self, value: f64) -> String {
+        let mut number = match self.decimals {
+            Some(decimals) if self.scientific => format!("{value:.decimals$e}"),
+            Some(decimals) => format!("{value:.decimals$}"),
+            None if self.align_decimal => rounded(value, self.fraction),
+            None => value.to_string(),
+        };
 
-            let size_req = cell.as_widget().size();
-            let height_factor = size_req.height.fill_factor();
+        if self.align_decimal && self.fraction > 0 && !self.scientific {
+            let digits = fraction(&number);
 
-            let max_height = if height_factor == 0 {
-                if size_req.height.is_fill() {
-                    metrics.rows[row]
-                } else {
-                    (available.height - y).max(0.0)
-                }
-            } else {
-                height_unit * height_factor as f32
-            };
+            if !number.contains('.') {
+                // Punctuation space, as wide as the decimal separator
+                number.push('\u{2008}');
+            }
 
-            // Force column width to Fixed(intrinsic + share)
-            let fixed = Length::Fixed(fixed_widths[column]);
+            // Figure spaces, as wide as a digit
+            number.extend(std::iter::repeat_n(
+                '\u{2007}',
+                self.fraction.saturating_sub(digits),
+            ));
+        }
 
-            let pass2_limits =
-                layout::Limits::new(Size::ZERO, Size::new(available.width - x, max_height))
-                    .width(fixed);
+        number
+    }
+}
 
-            let layout = cell.as_widget_mut().layout(state, renderer, &pass2_limits);
-            let sz = pass2_limits.resolve(fixed, Length::Shrink, layout.size());
+/// Takes the given excess width from the shrinkable columns, in proportion to
+/// their widths and without going below their minimums.
+///
+/// Returns the excess width left.
+fn shrink(widths: &mut [f32], minimums: &[f32], shrinkable: &[bool], mut excess: f32) -> f32 {
+    let mut shrinking: Vec<_> = (0..widths.len())
+        .filter(|&column| shrinkable[column] && widths[column] > minimums[column])
+        .collect();
 
-            // Row metric grows as usual
-            metrics.rows[row] = metrics.rows[row].max(sz.height);
+    // Columns reaching their minimum leave the rest to the others
+    while excess > 0.5 && !shrinking.is_empty() {
+        let total: f32 = shrinking.iter().map(|&column| widths[column]).sum();
 
-            cells[i] = layout;
-            x += fixed_widths[column] + spacing_x;
+        if total <= 0.0 {
+            break;
         }
 
-        // ---------- THIRD PASS (position) ----------
-        let mut x = self.padding_x;
-        let mut y = self.padding_y;
-
-        for (i, cell) in cells.iter_mut().enumerate() {
-            let row = i / columns;
-            let column = i % columns;
-
-            if column == 0 {
-                x = self.padding_x;
+        let mut taken = 0.0;
 
-                if row > 0 {
-                    y += metrics.rows[row - 1] + spacing_y;
-                }
-            }
+        for &column in &shrinking {
+            let share = excess * widths[column] / total;
+            let width = (widths[column] - share).max(minimums[column]);
 
-            let Column_ {
-                align_x, align_y, ..
-            } = &self.columns[column];
+            taken += widths[column] - width;
+            widths[column] = width;
+        }
 
-            cell.move_to_mut((x, y));
-            cell.align_mut(
-                Alignment::from(*align_x),
-                Alignment::from(*align_y),
-                Size::new(metrics.columns[column], metrics.rows[row]),
-            );
+        excess -= taken;
+        shrinking.retain(|&column| widths[column] > minimums[column]);
+    }
 
-            x += metrics.columns[column] + spacing_x;
-        }
+    excess.max(0.0)
+}
 
-        // Intrinsic table size
-        let intrinsic = limits.resolve(
-            self.width,
-            self.height,
-            Size::new(
-                // left pad + sum(fixed) + separators + right pad
-                x - spacing_x + self.padding_x,
-                // top pad + rows + inter-row spacing + bottom pad
-                self.padding_y * 2.0
-                    + metrics.rows.iter().sum::<f32>()
-                    + spacing_y * rows.saturating_sub(1) as f32
-                    - self.separator_y, // remove the last added separator_y
-            ),
-        );
+/// Reorders the columns of every row of the given items.
+fn reorder<T>(items: Vec<T>, order: &[usize]) -> Vec<T> {
+    let columns = order.len().max(1);
+    let mut items: Vec<_> = items.into_iter().map(Some).collect();
 
-        layout::Node::with_children(intrinsic, cells)
-    }
+    (0..items.len())
+        .map(|i| {
+            let row = i / columns * columns;
 
-    fn update(
-        &mut self,
-        tree: &mut tree::Tree,
-        event: &iced::Event,
-        layout: Layout<'_>,
-        cursor: mouse::Cursor,
-        renderer: &Renderer,
-        clipboard: &mut dyn advanced::Clipboard,
-        shell: &mut advanced::Shell<'_, Message>,
-        viewport: &Rectangle,
-    ) {
-        for ((cell, state), layout) in self
-            .cells
-            .iter_mut()
-            .zip(&mut tree.children)
-            .zip(layout.children())
-        {
-            cell.as_widget_mut().update(
-                state, event, layout, cursor, renderer, clipboard, shell, viewport,
-            );
-        }
-    }
+            items[row + order[i % columns]]
+                .take()
+                .expect("every item is taken once")
+        })
+        .collect()
+}
 
-    fn draw(
-        &self,
-        tree: &tree::Tree,
-        renderer: &mut Renderer,
-        theme: &Theme,
-        style: &renderer::Style,
-        layout: Layout<'_>,
-        cursor: mouse::Cursor,
-        viewport: &Rectangle,
-    ) {
-        for ((cell, state), layout) in self.cells.iter().zip(&tree.children).zip(layout.children())
-        {
-            cell.as_widget()
-                .draw(state, renderer, theme, style, layout, cursor, viewport);
-        }
+/// Replaces the characters that would break a tab-separated value.
+fn sanitize(value: &str) -> String {
+    value.replace(['\t', '\n', '\r'], " ")
+}
 
-        let bounds = layout.bounds();
-        let metrics = tree.state.downcast_ref::<Metrics>();
-        let style = theme.style(&self.class);
+/// Returns the amount of decimal places needed by the given magnitudes, up to
+/// three significant digits for the smallest one.
+fn precision(magnitudes: &[f64]) -> usize {
+    let smallest = magnitudes
+        .iter()
+        .copied()
+        .filter(|magnitude| *magnitude > 0.0)
+        .fold(f64::INFINITY, f64::min);
 
-        if self.separator_x > 0.0 {
-            let mut x = self.padding_x;
+    let fractions = magnitudes
+        .iter()
+        .map(|magnitude| fraction(&magnitude.to_string()))
+        .max()
+        .unwrap_or_default();
 
-            for width in &metrics.columns[..metrics.columns.len().saturating_sub(1)] {
-                x += width + self.padding_x;
+    // Three significant digits for the smallest value, at most
+    let significant = if smallest.is_finite() {
+        (2.0 - smallest.log10().floor()).clamp(0.0, 6.0) as usize
+    } else {
+        0
+    };
 
-                renderer.fill_quad(
-                    renderer::Quad {
-                        bounds: Rectangle {
-                            x: bounds.x + x,
-                            y: bounds.y,
-                            width: self.separator_x,
-                            height: bounds.height,
-                        },
-                        snap: true,
-                        ..renderer::Quad::default()
-                    },
-                    style.separator_x,
-                );
+    fractions.min(significant)
+}
 
-                x += self.separator_x + self.padding_x;
-            }
-        }
+/// Formats the given value with the given decimal places at most, without
+/// trailing zeros.
+fn rounded(value: f64, decimals: usize) -> String {
+    let number = format!("{value:.decimals$}");
 
-        if self.separator_y > 0.0 {
-            let mut y = self.padding_y;
+    if number.contains('.') {
+        number
+            .trim_end_matches('0')
+            .trim_end_matches('.')
+            .to_owned()
+    } else {
+        number
+    }
+}
 
-            for height in &metrics.rows[..metrics.rows.len().saturating_sub(1)] {
-                y += height + self.padding_y;
+fn fraction(number: &str) -> usize {
+    number
+        .find('.')
+        .map_or(0, |separator| number.len() - separator - 1)
+}
 
-                renderer.fill_quad(
-                    renderer::Quad {
-                        bounds: Rectangle {
-                            x: bounds.x,
-                            y: bounds.y + y,
-                            width: bounds.width,
-                            height: self.separator_y,
-                        },
-                        snap: true,
-                        ..renderer::Quad::default()
-                    },
-                    style.separator_y,
-                );
+/// An aggregate of the values of a [`Column`], shown below its header.
+///
+/// See [`Column::summary`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aggregate {
+    /// The amount of rows.
+    Count,
+    /// The sum of the numeric values.
+    Sum,
+    /// The mean of the numeric values.
+    Mean,
+}
 
-                y += self.separator_y + self.padding_y;
-            }
+impl Aggregate {
+    fn apply(self, rows: usize, values: &[f64], format: &Format) -> String {
+        match self {
+            Self::Count => rows.to_string(),
+            Self::Sum => format.number(values.iter().sum()),
+            Self::Mean if values.is_empty() => String::from("\u{2014}"),
+            Self::Mean => format.number(values.iter().sum::<f64>() / values.len() as f64),
         }
     }
+}
 
-    fn mouse_interaction(
-        &self,
-        tree: &tree::Tree,
-        layout: Layout<'_>,
-        cursor: mouse::Cursor,
-        viewport: &Rectangle,
-        renderer: &Renderer,
-    ) -> mouse::Interaction {
-        self.cells
+/// The aggregates of the numeric values of every [`Column`] of a [`Table`].
+///
+/// See [`computed_column`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Context {
+    rows: usize,
+    statistics: Vec<Statistics>,
+    sums: Vec<f64>,
+}
+
+impl Context {
+    fn new<'a, 'b, T, Message, Theme, Renderer>(
+        columns: &[Column<'a, 'b, T, Message, Theme, Renderer>],
+        rows: &[T],
+    ) -> Self {
+        let values: Vec<Vec<f64>> = columns
             .iter()
-            .zip(&tree.children)
-            .zip(layout.children())
-            .map(|((cell, state), layout)| {
-                cell.as_widget()
-                    .mouse_interaction(state, layout, cursor, viewport, renderer)
+            .map(|column| match &column.value {
+                Some(value) => rows.iter().filter_map(|row| value(row).number()).collect(),
+                None => Vec::new(),
             })
-            .max()
-            .unwrap_or_default()
-    }
+            .collect();
 
-    fn operate(
-        &mut self,
-        tree: &mut tree::Tree,
-        layout: Layout<'_>,
-        renderer: &Renderer,
-        operation: &mut dyn Operation,
-    ) {
-        for ((cell, state), layout) in self
-            .cells
-            .iter_mut()
-            .zip(&mut tree.children)
-            .zip(layout.children())
-        {
-            cell.as_widget_mut()
-                .operate(state, layout, renderer, operation);
+        Self {
+            rows: rows.len(),
+            sums: values.iter().map(|values| values.iter().sum()).collect(),
+            statistics: values
+                .into_iter()
+                .map(|values| values.into_iter().collect())
+                .collect(),
         }
     }
 
-    fn overlay<'b>(
-        &'b mut self,
-        state: &'b mut tree::Tree,
-        layout: Layout<'b>,
-        renderer: &Renderer,
-        viewport: &Rectangle,
-        translation: iced::Vector,
-    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
-        overlay::from_children(
-            &mut self.cells,
-            state,
-            layout,
-            renderer,
-            viewport,
-            translation,
-        )
+    /// Returns the amount of rows of the [`Table`].
+    pub fn rows(&self) -> usize {
+        self.rows
     }
-}
 
-impl<'a, Message, Theme, Renderer> From<Table<'a, Message, Theme, Renderer>>
-    for Element<'a, Message, Theme, Renderer>
-where
-    Message: 'a,
-    Theme: Catalog + 'a,
-    Renderer: R + 'a,
-{
-    fn from(table: Table<'a, Message, Theme, Renderer>) -> Self {
-        Element::new(table)
+    /// Returns the sum of the numeric values of the [`Column`] with the given
+    /// index, as given on construction.
+    pub fn sum(&self, column: usize) -> f64 {
+        self.sums.get(column).copied().unwrap_or_default()
+    }
+
+    /// Returns the [`Statistics`] of the numeric values of the [`Column`] with
+    /// the given index, as given on construction.
+    pub fn statistics(&self, column: usize) -> Statistics {
+        self.statistics.get(column).copied().unwrap_or_default()
     }
 }
 
-/// A vertical visualization of some data with a header.
-pub struct Column<'a, 'b, T, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
-    header: Element<'a, Message, Theme, Renderer>,
-    view: Box<dyn Fn(T) -> Element<'a, Message, Theme, Renderer> + 'b>,
-    width: Length,
-    align_x: alignment::Horizontal,
-    align_y: alignment::Vertical,
+/// The summary statistics of the numeric values of a [`Column`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Statistics {
+    /// The smallest value.
+    pub min: f64,
+    /// The largest value.
+    pub max: f64,
+    /// The arithmetic mean of the values.
+    pub mean: f64,
+    /// The amount of values.
+    pub count: usize,
 }
 
-impl<'a, 'b, T, Message, Theme, Renderer> Column<'a, 'b, T, Message, Theme, Renderer> {
-    /// Sets the width of the [`Column`].
-    pub fn width(mut self, width: impl Into<Length>) -> Self {
-        self.width = width.into();
-        self
+impl Statistics {
+    fn push(&mut self, value: f64) {
+        if self.count == 0 {
+            self.min = value;
+            self.max = value;
+        } else {
+            self.min = self.min.min(value);
+            self.max = self.max.max(value);
+        }
+
+        self.count += 1;
+        self.mean += (value - self.mean) / self.count as f64;
     }
+}
 
-    /// Sets the alignment for the horizontal axis of the [`Column`].
-    pub fn align_x(mut self, alignment: impl Into<alignment::Horizontal>) -> Self {
-        self.align_x = alignment.into();
-        self
+impl FromIterator<f64> for Statistics {
+    fn from_iter<I: IntoIterator<Item = f64>>(values: I) -> Self {
+        let mut statistics = Self::default();
+
+        for value in values {
+            statistics.push(value);
+        }
+
+        statistics
     }
+}
 
-    /// Sets the alignment for the vertical axis of the [`Column`].
-    pub fn align_y(mut self, alignment: impl Into<alignment::Vertical>) -> Self {
-        self.align_y = alignment.into();
-        self
+impl std::fmt::Display for Statistics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "min: {}\nmax: {}\nmean: {}\ncount: {}",
+            self.min, self.max, self.mean, self.count
+        )
     }
 }
 
 /// The appearance of a [`Table`].
 #[derive(Debug, Clone, Copy)]
 pub struct Style {
+    /// The background of the [`Table`], if any.
+    pub background: Option<Background>,
+    /// The color of the text of the cells, if it overrides the inherited one.
+    pub text_color: Option<Color>,
     /// The background color of the horizontal line separator between cells.
     pub separator_x: Background,
     /// The background color of the vertical line separator between cells.
     pub separator_y: Background,
+    /// The background color of the line separators between the cells of the
+    /// header.
+    pub separator_header_x: Background,
+    /// The background color of the line separator below the header.
+    pub separator_header_y: Background,
+    /// The background color of the line separators around the hovered column,
+    /// if they are highlighted.
+    pub separator_hovered: Option<Background>,
+    /// The background color of the hovered row, if it is highlighted.
+    pub row_hovered: Option<Background>,
+    /// The background color of the gutter with the row numbers.
+    pub gutter: Background,
+    /// The background color of the scroller of the horizontal scrollbar.
+    pub scroller: Background,
+    /// The [`Border`] of the scroller of the horizontal scrollbar.
+    pub scroller_border: Border,
+    /// The background color of the rail of the horizontal scrollbar, if any.
+    pub rail: Option<Background>,
+    /// The background color of the added rows of a [`compare`] table.
+    pub added: Background,
+    /// The background color of the removed rows of a [`compare`] table.
+    pub removed: Background,
+    /// The background color of the changed rows of a [`compare`] table.
+    pub changed: Background,
+    /// The background color of a pressed cell. See [`Column::on_press`].
+    pub pressed: Background,
+    /// The background color of the baseline row. See [`baseline()`].
+    pub baseline: Background,
+    /// The background color of the cell a dragged cell would be dropped on.
+    /// See [`Column::draggable`].
+    pub drop_target: Background,
+    /// The background color of the insert and remove affordances of a
+    /// header. See [`Table::on_column_insert`].
+    pub affordance: Background,
+    /// The color of the icons of the insert and remove affordances.
+    pub affordance_icon: Color,
+    /// The background dimming the body of a busy [`Table`]. See [`Table::busy`].
+    pub busy: Background,
+    /// The color of the spinner of a busy [`Table`].
+    pub spinner: Color,
 }
 
 /// The theme catalog of a [`Table`].
@@ -674,7 +6294,61 @@ pub fn default(theme: &iced::Theme) -> Style {
     let separator = palette.background.strong.color.into();
 
     Style {
+        background: None,
+        text_color: None,
         separator_x: separator,
         separator_y: separator,
+        separator_header_x: separator,
+        separator_header_y: separator,
+        separator_hovered: None,
+        row_hovered: Some(palette.background.weak.color.scale_alpha(0.5).into()),
+        gutter: palette.background.weak.color.into(),
+        scroller: palette.background.strongest.color.into(),
+        scroller_border: border::rounded(3),
+        rail: None,
+        added: palette.success.weak.color.scale_alpha(0.5).into(),
+        removed: palette.danger.weak.color.scale_alpha(0.5).into(),
+        changed: palette.warning.weak.color.scale_alpha(0.5).into(),
+        pressed: palette.primary.weak.color.scale_alpha(0.5).into(),
+        baseline: palette.secondary.weak.color.scale_alpha(0.5).into(),
+        drop_target: palette.primary.weak.color.scale_alpha(0.5).into(),
+        affordance: palette.primary.strong.color.into(),
+        affordance_icon: palette.primary.strong.text,
+        busy: palette.background.base.color.scale_alpha(0.6).into(),
+        spinner: palette.primary.strong.color,
+    }
+}
+
+/// A print-friendly monochrome style of a [`Table`], regardless of the theme.
+///
+/// Black text and separators on a white background, without hover or press
+/// highlights. Changed rows are shaded in gray.
+pub fn monochrome(_theme: &iced::Theme) -> Style {
+    let black = Background::from(Color::BLACK);
+    let shade = Background::from(Color::from_rgb8(0xE0, 0xE0, 0xE0));
+
+    Style {
+        background: Some(Color::WHITE.into()),
+        text_color: Some(Color::BLACK),
+        separator_x: black,
+        separator_y: black,
+        separator_header_x: black,
+        separator_header_y: black,
+        separator_hovered: None,
+        row_hovered: None,
+        gutter: Color::WHITE.into(),
+        scroller: black,
+        scroller_border: border::rounded(3),
+        rail: None,
+        added: shade,
+        removed: shade,
+        changed: shade,
+        pressed: Color::TRANSPARENT.into(),
+        baseline: shade,
+        drop_target: Color::TRANSPARENT.into(),
+        affordance: black,
+        affordance_icon: Color::WHITE,
+        busy: Color::WHITE.scale_alpha(0.6).into(),
+        spinner: Color::BLACK,
     }
 }
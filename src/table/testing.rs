@@ -0,0 +1,426 @@
+//! Test the layout of a [`Table`] without a renderer.
+//!
+//! [`Table`]: super::Table
+use super::{Config, Sizing, Table, fluid};
+
+use iced::advanced::clipboard::{self, Clipboard};
+use iced::advanced::widget::operation::{self, Operation, Outcome};
+use iced::advanced::widget::tree;
+use iced::advanced::{Layout, Shell, Widget, layout};
+use iced::keyboard::{self, key};
+use iced::mouse;
+use iced::{Event, Length, Point, Rectangle, Size, Theme};
+
+/// The widths and heights computed by the layout of a [`Table`].
+///
+/// [`Table`]: super::Table
+#[derive(Debug, Clone, PartialEq)]
+pub struct Metrics {
+    /// The width of every column, without padding.
+    pub columns: Vec<f32>,
+    /// The height of every row, starting with the header, without padding.
+    pub rows: Vec<f32>,
+    /// The total size of the [`Table`].
+    ///
+    /// [`Table`]: super::Table
+    pub size: Size,
+    /// The body cells being flashed, as row and column, in order.
+    pub flashing: Vec<(usize, usize)>,
+}
+
+/// Computes the [`Metrics`] of a [`Table`] with the given amount of columns
+/// and rows, using the default padding and separators.
+///
+/// The `measure` function produces the size of the cell in the given row and
+/// column within the given limits. The header is the first row. See [`Grid`]
+/// to size the columns.
+///
+/// [`Table`]: super::Table
+pub fn layout(
+    columns: usize,
+    rows: usize,
+    limits: &layout::Limits,
+    measure: impl Fn(usize, usize, &layout::Limits) -> Size,
+) -> Metrics {
+    Grid::new(columns, rows).layout(limits, measure)
+}
+
+/// The columns and rows of a [`Table`] to be laid out, sized like the columns
+/// of a [`Table`].
+///
+/// [`Table`]: super::Table
+#[derive(Debug, Clone)]
+pub struct Grid {
+    sizings: Vec<Sizing>,
+    rows: usize,
+    config: Config,
+}
+
+impl Grid {
+    /// Creates a new [`Grid`] with the given amount of columns and rows, using
+    /// the default padding and separators.
+    pub fn new(columns: usize, rows: usize) -> Self {
+        Self {
+            sizings: vec![Sizing::default(); columns],
+            rows,
+            config: Config {
+                padding_x: 10.0,
+                padding_y: 5.0,
+                separator_x: 1.0,
+                separator_y: 1.0,
+                min_width: 0.0,
+                resize_step: None,
+                shrink: false,
+            },
+        }
+    }
+
+    /// Fixes the width of the given column, like [`Column::fixed`].
+    ///
+    /// [`Column::fixed`]: super::Column::fixed
+    pub fn fixed(mut self, column: usize, width: f32) -> Self {
+        self.sizings[column].fixed = Some(width.max(0.0));
+        self
+    }
+
+    /// Sets the width of the given column as a percentage, like
+    /// [`Column::width_percent`].
+    ///
+    /// [`Column::width_percent`]: super::Column::width_percent
+    pub fn width_percent(mut self, column: usize, percent: f32) -> Self {
+        self.sizings[column].percent = Some(percent.max(0.0));
+        self
+    }
+
+    /// Sets the weight of the given column, like a [`Column::width`] of
+    /// [`Length::FillPortion`]. A weight of zero keeps the column at its
+    /// intrinsic width, like [`Column::auto_fit`].
+    ///
+    /// [`Column::width`]: super::Column::width
+    /// [`Column::auto_fit`]: super::Column::auto_fit
+    pub fn weight(mut self, column: usize, weight: f32) -> Self {
+        self.sizings[column].weight = weight;
+        self
+    }
+
+    /// Sets the shrink priority of the given column, like
+    /// [`Column::shrink_priority`].
+    ///
+    /// [`Column::shrink_priority`]: super::Column::shrink_priority
+    pub fn shrink_priority(mut self, column: usize, priority: u8) -> Self {
+        self.sizings[column].priority = priority;
+        self
+    }
+
+    /// Sets the minimum width of the [`Grid`], like [`Table::min_width`].
+    ///
+    /// [`Table::min_width`]: super::Table::min_width
+    pub fn min_width(mut self, width: f32) -> Self {
+        self.config.min_width = width;
+        self
+    }
+
+    /// Shrinks the columns to fit, like [`Table::shrink_to_fit`].
+    ///
+    /// [`Table::shrink_to_fit`]: super::Table::shrink_to_fit
+    pub fn shrink_to_fit(mut self) -> Self {
+        self.config.shrink = true;
+        self
+    }
+
+    /// Computes the [`Metrics`] of the [`Grid`] within the given limits.
+    ///
+    /// The `measure` function produces the size of the cell in the given row
+    /// and column within the given limits. The header is the first row.
+    pub fn layout(
+        &self,
+        limits: &layout::Limits,
+        measure: impl Fn(usize, usize, &layout::Limits) -> Size,
+    ) -> Metrics {
+        let columns = self.sizings.len();
+        let limits = limits.width(Length::Fill).height(Length::Shrink);
+        let sizes = vec![Size::new(Length::Shrink, Length::Shrink); columns * (self.rows + 1)];
+        let mut metrics = super::Metrics::default();
+
+        // Columns stop shrinking at the minimum width; the rest is scrolled
+        let available = limits.max();
+        let available = Size::new(available.width.max(self.config.min_width), available.height);
+
+        fluid(
+            self.config,
+            &mut metrics,
+            &self.sizings,
+            available,
+            limits.max().width,
+            &sizes,
+            |i, limits| measure(i / columns, i % columns, limits),
+        );
+
+        let size = limits.resolve(Length::Fill, Length::Shrink, self.config.content(&metrics));
+
+        Metrics {
+            columns: metrics.columns,
+            rows: metrics.rows,
+            size,
+            flashing: Vec::new(),
+        }
+    }
+}
+
+/// Measures the given text like a monospaced font, wrapping words to fit the
+/// given limits.
+///
+/// Every character is 8 units wide and every line is 20 units tall.
+pub fn text(content: &str, limits: &layout::Limits) -> Size {
+    const CHARACTER: f32 = 8.0;
+    const LINE: f32 = 20.0;
+
+    let max_width = limits.max().width;
+    let mut lines = 1;
+    let mut line = 0.0;
+    let mut width: f32 = 0.0;
+
+    for word in content.split(' ') {
+        let word_width = word.chars().count() as f32 * CHARACTER;
+
+        if line > 0.0 && line + CHARACTER + word_width > max_width {
+            width = width.max(line);
+            lines += 1;
+            line = word_width;
+        } else if line > 0.0 {
+            line += CHARACTER + word_width;
+        } else {
+            line = word_width;
+        }
+    }
+
+    let size = Size::new(width.max(line), lines as f32 * LINE);
+
+    limits.resolve(Length::Shrink, Length::Shrink, size)
+}
+
+/// A headless [`Table`] that can receive simulated events.
+///
+/// The [`Table`] is laid out with the null renderer, so text has no size
+/// unless the cells have a fixed one.
+///
+/// [`Table`]: super::Table
+pub struct Simulator<'a, Message> {
+    table: Table<'a, Message, Theme, ()>,
+    tree: tree::Tree,
+    layout: layout::Node,
+    size: Size,
+    cursor: Point,
+    messages: Vec<Message>,
+    clipboard: Recorder,
+}
+
+impl<'a, Message> Simulator<'a, Message> {
+    /// Creates a new [`Simulator`] for the given [`Table`] with the given size.
+    ///
+    /// [`Table`]: super::Table
+    pub fn new(mut table: Table<'a, Message, Theme, ()>, size: impl Into<Size>) -> Self {
+        let mut tree = tree::Tree::new(&table as &dyn Widget<Message, Theme, ()>);
+        let size = size.into();
+
+        let layout = table.layout(&mut tree, &(), &layout::Limits::new(Size::ZERO, size));
+
+        Self {
+            table,
+            tree,
+            layout,
+            size,
+            cursor: Point::ORIGIN,
+            messages: Vec::new(),
+            clipboard: Recorder::default(),
+        }
+    }
+
+    /// Returns the center of the cell in the given row and column, as seen
+    /// on screen.
+    ///
+    /// The header is the first row, below the headers of any groups.
+    pub fn cell(&self, row: usize, column: usize) -> Point {
+        let metrics = self.tree.state.downcast_ref::<super::Metrics>();
+
+        let (Some(width), Some(height)) = (metrics.columns.get(column), metrics.rows.get(row))
+        else {
+            return Point::ORIGIN;
+        };
+
+        let top = if row == 0 { metrics.tier } else { 0.0 };
+
+        Point::new(
+            self.table.column_x(metrics, column) - metrics.scroll_x + width / 2.0,
+            self.table.row_y(metrics, row) + top + (height - top) / 2.0,
+        )
+    }
+
+    /// Replaces the [`Table`] with the given one, like a new view would, and
+    /// lays it out again.
+    ///
+    /// [`Table`]: super::Table
+    pub fn view(&mut self, table: Table<'a, Message, Theme, ()>) {
+        self.table = table;
+        self.tree
+            .diff(&self.table as &dyn Widget<Message, Theme, ()>);
+
+        self.layout = self.table.layout(
+            &mut self.tree,
+            &(),
+            &layout::Limits::new(Size::ZERO, self.size),
+        );
+    }
+
+    /// Moves the cursor to the given position.
+    pub fn move_to(&mut self, position: Point) {
+        self.cursor = position;
+        self.event(Event::Mouse(mouse::Event::CursorMoved { position }));
+    }
+
+    /// Clicks at the given position.
+    pub fn click(&mut self, position: Point) {
+        self.move_to(position);
+        self.event(Event::Mouse(mouse::Event::ButtonPressed(
+            mouse::Button::Left,
+        )));
+        self.event(Event::Mouse(mouse::Event::ButtonReleased(
+            mouse::Button::Left,
+        )));
+    }
+
+    /// Drags the cursor from one position to another.
+    pub fn drag(&mut self, from: Point, to: Point) {
+        self.move_to(from);
+        self.event(Event::Mouse(mouse::Event::ButtonPressed(
+            mouse::Button::Left,
+        )));
+        self.move_to(to);
+        self.event(Event::Mouse(mouse::Event::ButtonReleased(
+            mouse::Button::Left,
+        )));
+    }
+
+    /// Presses Ctrl+C.
+    pub fn copy(&mut self) {
+        self.event(Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Character("c".into()),
+            modified_key: keyboard::Key::Character("c".into()),
+            physical_key: key::Physical::Code(key::Code::KeyC),
+            location: keyboard::Location::Standard,
+            modifiers: keyboard::Modifiers::CTRL,
+            text: Some("c".into()),
+            repeat: false,
+        }));
+    }
+
+    /// Feeds the given [`Event`] to the [`Table`] and lays it out again.
+    ///
+    /// [`Table`]: super::Table
+    pub fn event(&mut self, event: Event) {
+        let mut shell = Shell::new(&mut self.messages);
+
+        self.table.update(
+            &mut self.tree,
+            &event,
+            Layout::new(&self.layout),
+            mouse::Cursor::Available(self.cursor),
+            &(),
+            &mut self.clipboard,
+            &mut shell,
+            &Rectangle::with_size(self.size),
+        );
+
+        self.layout = self.table.layout(
+            &mut self.tree,
+            &(),
+            &layout::Limits::new(Size::ZERO, self.size),
+        );
+    }
+
+    /// Runs the given [`Operation`] on the [`Table`], following its chain
+    /// until it produces an output, if any.
+    ///
+    /// [`Table`]: super::Table
+    pub fn operate<T: 'static>(&mut self, operation: impl Operation<T> + 'static) -> Option<T> {
+        let mut operation: Box<dyn Operation<T>> = Box::new(operation);
+
+        loop {
+            self.table.operate(
+                &mut self.tree,
+                Layout::new(&self.layout),
+                &(),
+                &mut operation::black_box(operation.as_mut()),
+            );
+
+            match operation.finish() {
+                Outcome::None => return None,
+                Outcome::Some(output) => return Some(output),
+                Outcome::Chain(next) => operation = next,
+            }
+        }
+    }
+
+    /// Resizes every column to the intrinsic width of its contents, like the
+    /// [`autofit`] task.
+    ///
+    /// [`autofit`]: super::autofit
+    pub fn autofit(&mut self) {
+        self.tree.state.downcast_mut::<super::Metrics>().autofit = super::Reset::Pending;
+        self.layout = self.table.layout(
+            &mut self.tree,
+            &(),
+            &layout::Limits::new(Size::ZERO, self.size),
+        );
+
+        self.move_to(self.cursor);
+    }
+
+    /// Returns the messages produced since the last call.
+    pub fn messages(&mut self) -> Vec<Message> {
+        std::mem::take(&mut self.messages)
+    }
+
+    /// Returns the contents last written to the clipboard, if any.
+    pub fn clipboard(&self) -> Option<&str> {
+        self.clipboard.contents.as_deref()
+    }
+
+    /// Returns the current [`Metrics`] of the [`Table`].
+    ///
+    /// [`Table`]: super::Table
+    pub fn metrics(&self) -> Metrics {
+        let metrics = self.tree.state.downcast_ref::<super::Metrics>();
+
+        let columns = metrics.columns.len().max(1);
+        let mut flashing: Vec<_> = metrics
+            .flashes
+            .keys()
+            .map(|cell| (cell / columns, cell % columns))
+            .collect();
+
+        flashing.sort_unstable();
+
+        Metrics {
+            columns: metrics.columns.clone(),
+            rows: metrics.rows.clone(),
+            size: self.layout.size(),
+            flashing,
+        }
+    }
+}
+
+#[derive(Default)]
+struct Recorder {
+    contents: Option<String>,
+}
+
+impl Clipboard for Recorder {
+    fn read(&self, _kind: clipboard::Kind) -> Option<String> {
+        self.contents.clone()
+    }
+
+    fn write(&mut self, _kind: clipboard::Kind, contents: String) {
+        self.contents = Some(contents);
+    }
+}
@@ -0,0 +1,24 @@
+use iced::advanced::widget::operation::{focusable, scope};
+use iced::widget::{Id, Space, text_input};
+use iced_table_fluid::table::{self, testing::Simulator};
+
+fn focused(simulator: &mut Simulator<'_, ()>, row: usize) -> Option<Id> {
+    simulator.operate(focusable::unfocus::<()>());
+    simulator.operate(scope(table::cell_id(row, 0), focusable::focus_next::<()>()));
+    simulator.operate(focusable::find_focused())
+}
+
+#[test]
+fn deltas_are_computed_against_the_baseline() {
+    let column = table::column(Space::new().width(50), |row: u32| {
+        text_input("", "").id(format!("value-{row}"))
+    })
+    .delta(|row: &u32, baseline: &u32| text_input("", "").id(format!("delta-{row}-{baseline}")));
+
+    let table: table::Table<'_, (), iced::Theme, ()> = table::baseline([column], 10, [12, 7]);
+    let mut simulator = Simulator::new(table, (400.0, 200.0));
+
+    assert_eq!(focused(&mut simulator, 0), Some(Id::from("value-10")));
+    assert_eq!(focused(&mut simulator, 1), Some(Id::from("delta-12-10")));
+    assert_eq!(focused(&mut simulator, 2), Some(Id::from("delta-7-10")));
+}
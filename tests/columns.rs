@@ -0,0 +1,91 @@
+use iced::Vector;
+use iced::widget::Space;
+use iced_table_fluid::table::{self, Separator, testing::Simulator};
+
+#[derive(Debug, Clone, PartialEq)]
+enum Message {
+    DoubleClicked(usize),
+    Separator(Separator),
+    Insert(usize),
+    Remove(usize),
+}
+
+/// Three columns as given on construction, the first one hidden and the
+/// others shown in reverse order.
+fn reordered<'a>() -> table::Table<'a, Message, iced::Theme, ()> {
+    let columns = (0..3).map(|column| {
+        table::column(Space::new().width(50), |_: usize| Space::new().width(50)).hidden(column == 0)
+    });
+
+    table::table(columns, 0..2).column_order(&[2, 1, 0])
+}
+
+#[test]
+fn double_clicking_a_reordered_header() {
+    let table = reordered().on_header_double_click(Message::DoubleClicked);
+    let mut simulator = Simulator::new(table, (400.0, 100.0));
+
+    simulator.click(simulator.cell(0, 0));
+    simulator.click(simulator.cell(0, 0));
+
+    assert_eq!(simulator.messages(), vec![Message::DoubleClicked(2)]);
+}
+
+#[test]
+fn clicking_the_separator_of_a_reordered_column() {
+    let table = reordered().on_separator_click(Message::Separator);
+    let mut simulator = Simulator::new(table, (400.0, 100.0));
+    let width = simulator.metrics().columns[0];
+
+    simulator.click(simulator.cell(1, 0) + Vector::new(width / 2.0 + 10.5, 0.0));
+
+    assert_eq!(
+        simulator.messages(),
+        vec![Message::Separator(Separator::Column(2))]
+    );
+}
+
+#[test]
+fn inserting_and_removing_around_a_reordered_column() {
+    let table = reordered()
+        .on_column_insert(Message::Insert)
+        .on_column_remove(Message::Remove);
+
+    let mut simulator = Simulator::new(table, (400.0, 100.0));
+    let width = simulator.metrics().columns[0];
+    let header = simulator.cell(0, 0);
+
+    // The badges sit inside the right edge and over the right separator
+    let remove = header + Vector::new(width / 2.0 - 7.0, 0.0);
+    simulator.move_to(remove);
+    simulator.click(remove);
+
+    let insert = header + Vector::new(width / 2.0 + 10.5, 0.0);
+    simulator.move_to(header);
+    simulator.click(insert);
+
+    assert_eq!(
+        simulator.messages(),
+        vec![Message::Remove(2), Message::Insert(3)]
+    );
+}
+
+#[test]
+fn spanning_cells_keep_their_own_columns_and_grow_their_last_row() {
+    let columns = (0..2).map(|column| {
+        table::column(Space::new().width(50), move |row: usize| {
+            match (row, column) {
+                (0, 0) => table::cell(Space::new().width(300).height(20)).span(2),
+                (1, 0) => table::cell(Space::new().width(50).height(100)).row_span(2),
+                _ => table::cell(Space::new().width(50).height(20)),
+            }
+        })
+    });
+
+    let table: table::Table<'_, (), iced::Theme, ()> = table::table(columns, 0..3);
+    let metrics = Simulator::new(table, (400.0, 400.0)).metrics();
+
+    // Spanning cells are laid out across the columns and rows they span
+    assert_eq!(metrics.columns, vec![179.5, 179.5]);
+    assert_eq!(metrics.rows[1..], [20.0, 20.0, 100.0 - 20.0 - 11.0]);
+}
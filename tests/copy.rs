@@ -0,0 +1,98 @@
+use iced::widget::Space;
+use iced::{Point, Size};
+use iced_table_fluid::table::{self, testing::Simulator};
+
+type Person = (&'static str, u32);
+
+#[test]
+fn copying_a_hovered_table() {
+    let columns = [
+        table::column(Space::new().width(50), |_: Person| Space::new().width(10))
+            .export("Name", |(name, _)| name.to_string()),
+        table::column(Space::new().width(50), |_: Person| Space::new().width(10))
+            .export("Age", |(_, age)| age.to_string()),
+        table::column(Space::new().width(50), |_: Person| Space::new().width(10)),
+    ];
+
+    let table: table::Table<'_, (), iced::Theme, ()> =
+        table::table(columns, [("Ada", 36), ("Alan\tTuring", 41)]);
+
+    let mut simulator = Simulator::new(table, Size::new(400.0, 200.0));
+
+    simulator.move_to(Point::new(500.0, 10.0));
+    simulator.copy();
+    assert_eq!(simulator.clipboard(), None);
+
+    simulator.move_to(Point::new(10.0, 10.0));
+    simulator.copy();
+    assert_eq!(
+        simulator.clipboard(),
+        Some("Name\tAge\nAda\t36\nAlan Turing\t41")
+    );
+}
+
+#[test]
+fn copying_reordered_columns() {
+    let columns = [
+        table::column(Space::new().width(50), |_: Person| Space::new().width(10))
+            .export("Name", |(name, _)| name.to_string()),
+        table::column(Space::new().width(50), |_: Person| Space::new().width(10))
+            .export("Age", |(_, age)| age.to_string()),
+    ];
+
+    let table: table::Table<'_, (), iced::Theme, ()> =
+        table::table(columns, [("Ada", 36)]).column_order(&[1, 0]);
+
+    let mut simulator = Simulator::new(table, Size::new(400.0, 200.0));
+
+    simulator.move_to(Point::new(10.0, 10.0));
+    simulator.copy();
+    assert_eq!(simulator.clipboard(), Some("Age\tName\n36\tAda"));
+}
+
+#[test]
+fn copying_values_without_export() {
+    let columns = [
+        table::numeric(Space::new(), |(_, age): &Person| f64::from(*age)).hidden(true),
+        table::column(Space::new().width(50), |_: Person| Space::new().width(10))
+            .export("Name", |(name, _)| name.to_string()),
+        table::computed_column(Space::new(), |(_, age): &Person, context| {
+            table::Value::Number(f64::from(*age) / context.sum(0) * 100.0)
+        })
+        .title("Share"),
+    ];
+
+    let table: table::Table<'_, (), iced::Theme, ()> =
+        table::table(columns, [("Ada", 36), ("Alan", 64)]);
+
+    let mut simulator = Simulator::new(table, Size::new(400.0, 200.0));
+
+    simulator.move_to(Point::new(10.0, 10.0));
+    simulator.copy();
+    assert_eq!(
+        simulator.clipboard(),
+        Some("Name\tShare\nAda\t36\nAlan\t64")
+    );
+}
+
+#[test]
+fn ordering_columns_after_a_hidden_one() {
+    let columns = [
+        table::column(Space::new().width(50), |_: Person| Space::new().width(10))
+            .export("Hidden", |_| String::new())
+            .hidden(true),
+        table::column(Space::new().width(50), |_: Person| Space::new().width(10))
+            .export("Name", |(name, _)| name.to_string()),
+        table::column(Space::new().width(50), |_: Person| Space::new().width(10))
+            .export("Age", |(_, age)| age.to_string()),
+    ];
+
+    let table: table::Table<'_, (), iced::Theme, ()> =
+        table::table(columns, [("Ada", 36)]).column_order(&[2, 1]);
+
+    let mut simulator = Simulator::new(table, Size::new(400.0, 200.0));
+
+    simulator.move_to(Point::new(10.0, 10.0));
+    simulator.copy();
+    assert_eq!(simulator.clipboard(), Some("Age\tName\n36\tAda"));
+}
@@ -0,0 +1,48 @@
+use iced::widget::Space;
+use iced::{Point, Vector};
+use iced_table_fluid::table::{self, RowColumn, testing::Simulator};
+
+#[derive(Debug, Clone, PartialEq)]
+enum Message {
+    Dropped(RowColumn, RowColumn),
+}
+
+fn simulator() -> Simulator<'static, Message> {
+    let columns = (0..3).map(|column| {
+        table::column(Space::new().width(50), |_: usize| {
+            Space::new().width(50).height(20)
+        })
+        .hidden(column == 0)
+        .draggable()
+    });
+
+    let table: table::Table<'_, Message, iced::Theme, ()> =
+        table::table(columns, 0..3).on_cell_drop(Message::Dropped);
+
+    Simulator::new(table, (400.0, 200.0))
+}
+
+#[test]
+fn dropping_a_cell_on_another_one() {
+    let mut simulator = simulator();
+
+    simulator.drag(simulator.cell(1, 0), simulator.cell(3, 1));
+
+    assert_eq!(
+        simulator.messages(),
+        vec![Message::Dropped(
+            RowColumn { row: 0, column: 1 },
+            RowColumn { row: 2, column: 2 },
+        )]
+    );
+}
+
+#[test]
+fn dropping_a_cell_outside_of_the_table() {
+    let mut simulator = simulator();
+    let outside = Point::new(0.0, simulator.metrics().size.height) + Vector::new(10.0, 10.0);
+
+    simulator.drag(simulator.cell(1, 0), outside);
+
+    assert_eq!(simulator.messages(), vec![]);
+}
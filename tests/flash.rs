@@ -0,0 +1,46 @@
+use std::time::Duration;
+
+use iced::Color;
+use iced::widget::Space;
+use iced_table_fluid::table::{self, testing::Simulator};
+
+type Stock = (&'static str, u32);
+
+fn table<'a>(rows: &[Stock]) -> table::Table<'a, (), iced::Theme, ()> {
+    let columns = [
+        table::column(Space::new(), |_: Stock| Space::new().width(50)),
+        table::numeric(Space::new(), |(_, price): &Stock| f64::from(*price)),
+    ];
+
+    table::compare(
+        columns,
+        rows.to_vec(),
+        rows.to_vec(),
+        |(name, _): &Stock| *name,
+    )
+    .flash_changed(Duration::from_secs(1), Color::WHITE)
+}
+
+#[test]
+fn flashing_a_moved_row_by_its_key() {
+    let mut simulator = Simulator::new(table(&[("a", 1), ("b", 2)]), (400.0, 200.0));
+
+    simulator.view(table(&[("b", 2), ("a", 5)]));
+
+    assert_eq!(simulator.metrics().flashing, vec![(1, 1)]);
+}
+
+#[test]
+fn flashing_by_position_without_keys() {
+    let view = |prices: [u32; 2]| {
+        let column = table::numeric(Space::new(), |price: &u32| f64::from(*price));
+
+        table::table([column], prices).flash_changed(Duration::from_secs(1), Color::WHITE)
+    };
+
+    let mut simulator: Simulator<'_, ()> = Simulator::new(view([1, 2]), (400.0, 200.0));
+
+    simulator.view(view([2, 2]));
+
+    assert_eq!(simulator.metrics().flashing, vec![(0, 0)]);
+}
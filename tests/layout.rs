@@ -0,0 +1,115 @@
+use iced::Size;
+use iced::advanced::layout::Limits;
+use iced_table_fluid::table::testing;
+
+const ROWS: [[&str; 2]; 3] = [["Name", "Age"], ["Ada Lovelace", "36"], ["Alan", "41"]];
+
+fn measure(row: usize, column: usize, limits: &Limits) -> Size {
+    testing::text(ROWS[row][column], limits)
+}
+
+#[test]
+fn text_wraps_words_to_fit() {
+    let limits = Limits::new(Size::ZERO, Size::new(100.0, 100.0));
+
+    assert_eq!(testing::text("Ada", &limits), Size::new(24.0, 20.0));
+    assert_eq!(
+        testing::text("Ada Lovelace Byron", &limits),
+        Size::new(96.0, 40.0)
+    );
+}
+
+#[test]
+fn columns_share_the_remaining_width() {
+    let limits = Limits::new(Size::ZERO, Size::new(400.0, 400.0));
+    let metrics = testing::layout(2, 2, &limits, measure);
+
+    // 400 - 2 * 10 of padding - 21 of spacing leaves 359, and 239 of it remains
+    assert_eq!(metrics.columns, vec![96.0 + 119.5, 24.0 + 119.5]);
+    assert_eq!(metrics.rows, vec![20.0, 20.0, 20.0]);
+    assert_eq!(metrics.size, Size::new(400.0, 91.0));
+}
+
+#[test]
+fn narrow_columns_wrap_down_to_their_longest_word() {
+    let limits = Limits::new(Size::ZERO, Size::new(100.0, 400.0));
+    let metrics = testing::layout(2, 2, &limits, measure);
+
+    assert_eq!(metrics.columns, vec![64.0, 24.0]);
+    assert_eq!(metrics.rows, vec![20.0, 40.0, 20.0]);
+    assert_eq!(metrics.size.width, 100.0);
+}
+
+fn grid(grid: testing::Grid) -> Vec<f32> {
+    let limits = Limits::new(Size::ZERO, Size::new(400.0, 400.0));
+
+    grid.layout(&limits, measure).columns
+}
+
+#[test]
+fn fixed_percent_and_weighted_columns() {
+    // 359 of content width, 120 of it intrinsic
+    assert_eq!(
+        grid(testing::Grid::new(2, 2).fixed(1, 100.0)),
+        vec![259.0, 100.0]
+    );
+    assert_eq!(
+        grid(testing::Grid::new(2, 2).width_percent(1, 50.0)),
+        vec![179.5, 179.5]
+    );
+    assert_eq!(
+        grid(testing::Grid::new(2, 2).weight(0, 3.0)),
+        vec![96.0 + 179.25, 24.0 + 59.75]
+    );
+    assert_eq!(
+        grid(testing::Grid::new(2, 2).weight(0, 0.0)),
+        vec![96.0, 263.0]
+    );
+}
+
+/// Headers 40 wide, and cells 200 and 100 wide, cut at the available width.
+fn wide(row: usize, column: usize, _limits: &Limits) -> Size {
+    let width = match (row, column) {
+        (0, _) => 40.0,
+        (_, 0) => 200.0,
+        _ => 100.0,
+    };
+
+    Size::new(width, 20.0)
+}
+
+#[test]
+fn shrinking_columns_by_priority() {
+    let limits = Limits::new(Size::ZERO, Size::new(300.0, 400.0));
+    let columns = |grid: testing::Grid| grid.layout(&limits, wide).columns;
+
+    // 259 of content width; the second column is cut at 69
+    assert_eq!(columns(testing::Grid::new(2, 2)), vec![200.0, 69.0]);
+    assert_eq!(
+        columns(
+            testing::Grid::new(2, 2)
+                .shrink_to_fit()
+                .shrink_priority(1, 1)
+        ),
+        vec![200.0, 59.0]
+    );
+    assert_eq!(
+        columns(
+            testing::Grid::new(2, 2)
+                .shrink_to_fit()
+                .shrink_priority(0, 1)
+        ),
+        vec![190.0, 69.0]
+    );
+}
+
+#[test]
+fn minimum_width_scrolls_the_columns() {
+    let limits = Limits::new(Size::ZERO, Size::new(200.0, 400.0));
+    let metrics = testing::Grid::new(2, 2)
+        .min_width(400.0)
+        .layout(&limits, wide);
+
+    assert_eq!(metrics.columns.iter().sum::<f32>() + 41.0, 400.0);
+    assert_eq!(metrics.size.width, 200.0);
+}
@@ -0,0 +1,63 @@
+use iced::Rectangle;
+use iced::advanced::widget::operation::{Operation, Outcome, scope};
+use iced::widget::{Id, Space};
+use iced_table_fluid::table::{self, testing::Simulator};
+
+/// Collects the text of the widgets it visits.
+#[derive(Default)]
+struct Texts(Vec<String>);
+
+impl Operation<Vec<String>> for Texts {
+    fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation<Vec<String>>)) {
+        operate(self);
+    }
+
+    fn text(&mut self, _id: Option<&Id>, _bounds: Rectangle, text: &str) {
+        self.0.push(text.to_owned());
+    }
+
+    fn finish(&self) -> Outcome<Vec<String>> {
+        Outcome::Some(self.0.clone())
+    }
+}
+
+fn texts(table: table::Table<'_, (), iced::Theme, ()>, rows: usize) -> Vec<String> {
+    let mut simulator = Simulator::new(table, (400.0, 200.0));
+
+    (0..rows)
+        .flat_map(|row| {
+            simulator
+                .operate(scope(table::cell_id(row, 0), Texts::default()))
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+fn cells(values: &[f64]) -> Vec<String> {
+    let column = table::numeric(Space::new(), |value: &f64| *value).align_decimal();
+
+    texts(table::table([column], values.iter().copied()), values.len())
+}
+
+#[test]
+fn aligning_decimals_of_float_noise() {
+    assert_eq!(
+        cells(&[0.1 + 0.2, 12.0, 2.25]),
+        vec!["0.3\u{2007}", "12\u{2008}\u{2007}\u{2007}", "2.25"]
+    );
+}
+
+#[test]
+fn units_and_missing_values_are_labels() {
+    let column =
+        table::numeric(Space::new(), |value: &f64| *value).unit("kg", table::Position::Suffix);
+
+    assert_eq!(texts(table::table([column], [1.5]), 1), vec!["1.5", "kg"]);
+
+    let column = table::optional_column(Space::new(), |value: Option<f64>| {
+        value.map(|_| Space::new())
+    })
+    .on_missing(table::Missing::Dash);
+
+    assert_eq!(texts(table::table([column], [None]), 1), vec!["\u{2014}"]);
+}
@@ -0,0 +1,93 @@
+use std::time::Duration;
+
+use iced::advanced::widget::operation::{focusable, scope};
+use iced::widget::{Id, Space, text_input};
+use iced_table_fluid::table::{self, CellColumn, testing::Simulator};
+
+fn simulator() -> Simulator<'static, ()> {
+    let columns = (0..3).map(|column| {
+        let cells = table::column(Space::new().width(50), move |row: usize| {
+            text_input("", "").id(format!("{row}-{column}"))
+        })
+        .hidden(column == 0);
+
+        if column == 2 { cells.id("last") } else { cells }
+    });
+
+    let table: table::Table<'_, (), iced::Theme, ()> =
+        table::table(columns, 0..2).column_order(&[2, 1, 0]);
+
+    Simulator::new(table, (400.0, 100.0))
+}
+
+#[test]
+fn focusing_a_cell_addressed_by_index() {
+    let mut simulator = simulator();
+
+    simulator.operate(scope(table::cell_id(1, 1), focusable::focus_next::<()>()));
+
+    assert_eq!(
+        simulator.operate(focusable::find_focused()),
+        Some(Id::from("1-1"))
+    );
+
+    simulator.operate(focusable::unfocus::<()>());
+    simulator.operate(scope(table::cell_id(0, 2), focusable::focus_next::<()>()));
+
+    assert_eq!(
+        simulator.operate(focusable::find_focused()),
+        Some(Id::from("0-2"))
+    );
+}
+
+#[test]
+fn focusing_a_cell_addressed_by_key() {
+    let mut simulator = simulator();
+
+    simulator.operate(scope(
+        table::cell_id(1, CellColumn::key("last")),
+        focusable::focus_next::<()>(),
+    ));
+
+    assert_eq!(
+        simulator.operate(focusable::find_focused()),
+        Some(Id::from("1-2"))
+    );
+}
+
+#[test]
+fn focusing_an_input_of_a_live_cell() {
+    let view = table::live_cell(Duration::from_secs(1), |row: &usize| {
+        text_input("", "").id(format!("live-{row}")).into()
+    });
+
+    let table: table::Table<'_, (), iced::Theme, ()> =
+        table::table([table::column(Space::new().width(50), view)], 0..2);
+
+    let mut simulator = Simulator::new(table, (400.0, 100.0));
+
+    simulator.operate(scope(table::cell_id(1, 0), focusable::focus_next::<()>()));
+
+    assert_eq!(
+        simulator.operate(focusable::find_focused()),
+        Some(Id::from("live-1"))
+    );
+}
+
+#[test]
+fn focusing_an_input_of_a_truncated_cell() {
+    let column = table::column(Space::new().width(50), |row: usize| {
+        text_input("", "").id(format!("truncated-{row}"))
+    })
+    .truncate();
+
+    let table: table::Table<'_, (), iced::Theme, ()> = table::table([column], 0..2);
+    let mut simulator = Simulator::new(table, (400.0, 100.0));
+
+    simulator.operate(scope(table::cell_id(0, 0), focusable::focus_next::<()>()));
+
+    assert_eq!(
+        simulator.operate(focusable::find_focused()),
+        Some(Id::from("truncated-0"))
+    );
+}
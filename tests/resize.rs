@@ -0,0 +1,74 @@
+use iced::widget::Space;
+use iced::{Point, Vector, mouse};
+use iced_table_fluid::table::{self, ResizeMode, testing::Simulator};
+
+#[derive(Debug, Clone, PartialEq)]
+enum Message {
+    Resized(usize, f32),
+}
+
+#[test]
+fn dragging_a_separator_of_a_scrolled_table() {
+    let columns =
+        (0..3).map(|_| table::column(Space::new().width(200), |_: usize| Space::new().width(200)));
+
+    let table: table::Table<'_, Message, iced::Theme, ()> = table::table(columns, 0..2)
+        .min_width(600)
+        .resizable(ResizeMode::Live)
+        .on_column_resize(Message::Resized);
+
+    let mut simulator = Simulator::new(table, (300.0, 100.0));
+
+    simulator.move_to(Point::new(150.0, 10.0));
+    simulator.event(iced::Event::Mouse(mouse::Event::WheelScrolled {
+        delta: mouse::ScrollDelta::Pixels { x: -100.0, y: 0.0 },
+    }));
+
+    // The first separator sits at 220.5 in the content, 120.5 on screen
+    simulator.drag(Point::new(120.5, 10.0), Point::new(150.5, 10.0));
+
+    assert_eq!(simulator.metrics().columns[0], 230.0);
+    assert_eq!(simulator.messages(), vec![Message::Resized(0, 230.0)]);
+}
+
+#[test]
+fn resizing_a_column_after_a_hidden_one() {
+    let columns = (0..3).map(|column| {
+        table::column(Space::new().width(100), |_: usize| Space::new().width(100))
+            .hidden(column == 0)
+    });
+
+    let table: table::Table<'_, Message, iced::Theme, ()> = table::table(columns, 0..2)
+        .resizable(ResizeMode::Live)
+        .on_column_resize(Message::Resized);
+
+    let mut simulator = Simulator::new(table, (400.0, 100.0));
+    let width = simulator.metrics().columns[0];
+    let separator = simulator.cell(0, 0) + Vector::new(width / 2.0 + 10.5, 0.0);
+
+    simulator.drag(separator, separator + Vector::new(20.0, 0.0));
+
+    assert_eq!(
+        simulator.messages(),
+        vec![Message::Resized(1, width + 20.0)]
+    );
+}
+
+#[test]
+fn autofit_reports_the_changed_columns() {
+    let columns = (0..2).map(|column| {
+        let cells = table::column(Space::new().width(100), |_: usize| Space::new().width(100));
+
+        if column == 0 { cells.auto_fit() } else { cells }
+    });
+
+    let table: table::Table<'_, Message, iced::Theme, ()> =
+        table::table(columns, 0..2).on_column_resize(Message::Resized);
+
+    let mut simulator = Simulator::new(table, (400.0, 100.0));
+
+    simulator.autofit();
+
+    assert_eq!(simulator.metrics().columns, vec![100.0, 100.0]);
+    assert_eq!(simulator.messages(), vec![Message::Resized(1, 100.0)]);
+}
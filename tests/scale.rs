@@ -0,0 +1,23 @@
+use iced::widget::Space;
+use iced_table_fluid::table::{self, testing::Simulator};
+
+fn scaled(scales: &[f32]) -> iced::Size {
+    let columns = [table::column(Space::new().width(50), |_: usize| {
+        Space::new().width(50).height(20)
+    })];
+
+    let table: table::Table<'_, (), iced::Theme, ()> = scales
+        .iter()
+        .fold(table::table(columns, 0..3), |table, scale| {
+            table.scale(*scale)
+        });
+
+    Simulator::new(table, (400.0, 400.0)).metrics().size
+}
+
+#[test]
+fn invalid_scales_are_ignored() {
+    assert_eq!(scaled(&[0.0, 2.0]), scaled(&[2.0]));
+    assert_eq!(scaled(&[f32::NAN, -1.0, f32::INFINITY]), scaled(&[]));
+    assert!(scaled(&[2.0]).height > scaled(&[]).height);
+}
@@ -0,0 +1,92 @@
+use iced::Vector;
+use iced::widget::Space;
+use iced_table_fluid::table::{self, ResizeMode, testing::Simulator};
+
+#[derive(Debug, Clone, PartialEq)]
+enum Message {
+    Pressed(usize),
+    Resized(usize, f32),
+}
+
+fn grouped<'a>() -> table::Table<'a, Message, iced::Theme, ()> {
+    let columns = table::column_group(
+        Space::new().width(10).height(30),
+        [
+            table::column(Space::new().width(50), |row: usize| {
+                Space::new().width(10 * row as u32)
+            })
+            .export("Row", |row| row.to_string()),
+            table::column(Space::new().width(50), |_: usize| Space::new().width(10))
+                .on_press(|row| Message::Pressed(*row))
+                .export("Double", |row| (row * 2).to_string()),
+        ],
+    )
+    .into_iter()
+    .chain([table::column(Space::new().width(50), |_: usize| {
+        Space::new().width(10)
+    })]);
+
+    table::table(columns, 0..3)
+        .row_numbers(true)
+        .resizable(ResizeMode::Live)
+        .on_column_resize(Message::Resized)
+}
+
+#[test]
+fn clicking_a_cell_below_groups_and_row_numbers() {
+    let mut simulator = Simulator::new(grouped(), (400.0, 300.0));
+
+    simulator.click(simulator.cell(3, 1));
+    assert_eq!(simulator.messages(), vec![Message::Pressed(2)]);
+
+    simulator.click(simulator.cell(2, 0));
+    assert_eq!(simulator.messages(), vec![]);
+}
+
+#[test]
+fn dragging_a_separator_below_groups_and_row_numbers() {
+    let mut simulator = Simulator::new(grouped(), (400.0, 300.0));
+    let width = simulator.metrics().columns[0];
+
+    // The separator sits between the padding of both columns
+    let separator = simulator.cell(1, 0) + Vector::new(width / 2.0 + 10.5, 0.0);
+
+    simulator.drag(separator, separator + Vector::new(25.0, 0.0));
+
+    assert_eq!(simulator.metrics().columns[0], width + 25.0);
+    assert_eq!(
+        simulator.messages(),
+        vec![Message::Resized(0, width + 25.0)]
+    );
+}
+
+#[test]
+fn copying_a_grouped_table() {
+    let mut simulator = Simulator::new(grouped(), (400.0, 300.0));
+
+    simulator.move_to(simulator.cell(2, 2));
+    simulator.copy();
+
+    assert_eq!(simulator.clipboard(), Some("Row\tDouble\n0\t0\n1\t2\n2\t4"));
+}
+
+#[test]
+fn hiding_the_first_column_of_a_group() {
+    let columns = table::column_group(
+        Space::new().width(10).height(30),
+        [
+            table::column(Space::new().width(50), |_: usize| Space::new().width(10)).hidden(true),
+            table::column(Space::new().width(50), |_: usize| Space::new().width(10))
+                .on_press(|row| Message::Pressed(*row)),
+        ],
+    );
+
+    let table: table::Table<'_, Message, iced::Theme, ()> = table::table(columns, 0..3);
+    let mut simulator = Simulator::new(table, (400.0, 300.0));
+
+    // The header of the group keeps its tier above the remaining column
+    assert_eq!(simulator.metrics().rows[0], 30.0 + 11.0);
+
+    simulator.click(simulator.cell(2, 0));
+    assert_eq!(simulator.messages(), vec![Message::Pressed(1)]);
+}
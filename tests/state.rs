@@ -0,0 +1,25 @@
+use iced::widget::Space;
+use iced_table_fluid::table::{self, State, testing::Simulator};
+
+fn widths(before: bool) -> Vec<f32> {
+    let columns = (0..3).map(|column| {
+        table::column(Space::new().width(50), |_: usize| Space::new().width(50)).hidden(column == 0)
+    });
+
+    let state = State::from_widths([Some(120.0), Some(80.0), Some(150.0)]);
+    let table: table::Table<'_, (), iced::Theme, ()> = table::table(columns, 0..2);
+
+    let table = if before {
+        table.state(&state).column_order(&[2, 1, 0])
+    } else {
+        table.column_order(&[2, 1, 0]).state(&state)
+    };
+
+    Simulator::new(table, (1000.0, 100.0)).metrics().columns
+}
+
+#[test]
+fn saved_widths_follow_the_construction_index() {
+    assert_eq!(widths(true), vec![150.0, 80.0]);
+    assert_eq!(widths(false), vec![150.0, 80.0]);
+}
@@ -0,0 +1,18 @@
+use iced::{Background, Color, Theme};
+use iced_table_fluid::table;
+
+#[test]
+fn monochrome_ignores_the_theme() {
+    for theme in [Theme::Light, Theme::Dark, Theme::Dracula] {
+        let style = table::monochrome(&theme);
+
+        assert_eq!(style.background, Some(Background::from(Color::WHITE)));
+        assert_eq!(style.text_color, Some(Color::BLACK));
+        assert_eq!(style.separator_x, Background::from(Color::BLACK));
+        assert_eq!(style.separator_header_y, Background::from(Color::BLACK));
+        assert_eq!(
+            style.changed,
+            Background::from(Color::from_rgb8(0xE0, 0xE0, 0xE0))
+        );
+    }
+}